@@ -6,7 +6,7 @@
 
 ///SPDX-License-Identifier: MIT
 ///OpenZeppelin Contracts (last updated v4.7.0) (access/AccessControl.sol)
-/// @dev Contract module that allows children to implement role-based access
+/// Contract module that allows children to implement role-based access
 /// control mechanisms. This is a lightweight version that doesn't allow enumerating role
 /// members except through off-chain means by accessing the contract event logs. Some
 /// applications may benefit from on-chain enumerability, for those cases see
@@ -58,24 +58,25 @@ pub mod access_control {
 
     pub const DEFAULT_ADMIN_ROLE: [u8; 32] = &hex::decode("0x00");
 
-    /// @dev Modifier that checks that an account has a specific role. Reverts
+    /// Modifier that checks that an account has a specific role. Reverts
     /// with a standardized message including the required role.
     /// The format of the revert reason is given by the following regular expression:
     /// /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
     /// _Available since v4.1._
-    ///The type of `T` should be the trait which implements the storage
-    ///This will be implemented in Sol2Ink in upcoming version
     #[modifier_definition]
-    pub fn only_role<T, F, R>(instance: &mut T, body: F, role: [u8; 32]) -> Result<R, Error>
+    pub fn only_role<F, R>(
+        instance: &mut AccessControl,
+        body: F,
+        role: [u8; 32],
+    ) -> Result<R, Error>
     where
-        T: AccessControl,
-        F: FnOnce(&mut T) -> Result<R, Error>,
+        F: FnOnce(&mut AccessControl) -> Result<R, Error>,
     {
-        self._check_role(role)?;
-        body(instance);
+        instance._check_role(role)?;
+        body(instance)
     }
 
-    /// @dev Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
+    /// Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
     /// `DEFAULT_ADMIN_ROLE` is the starting admin for all roles, despite
     /// {RoleAdminChanged} not being emitted signaling this.
     /// _Available since v3.1._
@@ -89,7 +90,7 @@ pub mod access_control {
         new_admin_role: [u8; 32],
     }
 
-    /// @dev Emitted when `account` is granted `role`.
+    /// Emitted when `account` is granted `role`.
     /// `sender` is the account that originated the contract call, an admin role
     /// bearer except when using {AccessControl-_setupRole}.
     #[ink(event)]
@@ -102,7 +103,7 @@ pub mod access_control {
         sender: AccountId,
     }
 
-    /// @dev Emitted when `account` is revoked `role`.
+    /// Emitted when `account` is revoked `role`.
     /// `sender` is the account that originated the contract call:
     /// - if using `revokeRole`, it is the admin role bearer
     /// - if using `renounceRole`, it is the role bearer (i.e. `account`)
@@ -116,10 +117,10 @@ pub mod access_control {
         sender: AccountId,
     }
 
-    #[derive(Default, Encode, Decode)]
+    ///Sol2Ink: field `members` of type `Mapping<AccountId, bool>` was removed, mappings can not be encoded inside of a struct, move it to the contract storage with the key of the struct as a part of its key
+    #[derive(Default, Clone, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct RoleData {
-        members: Mapping<AccountId, bool>,
         admin_role: [u8; 32],
     }
 
@@ -144,14 +145,15 @@ pub mod access_control {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {})
         }
 
-        /// @dev See {IERC165-supportsInterface}.
+        /// See {IERC165-supportsInterface}.
         #[ink(message)]
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
-            return Ok(interface_id == i_access_control.interface_id
+            return Ok(interface_id
+                == todo!("Sol2Ink Not Implemented yet: type(IAccessControl).interfaceId")
                 || super.supports_interface(interface_id)?)
         }
 
-        /// @dev Returns `true` if `account` has been granted `role`.
+        /// Returns `true` if `account` has been granted `role`.
         #[ink(message)]
         pub fn has_role(&self, role: [u8; 32], account: AccountId) -> Result<bool, Error> {
             return Ok(self
@@ -164,33 +166,35 @@ pub mod access_control {
                 .unwrap_or_default())
         }
 
-        /// @dev Revert with a standard message if `msg.sender` is missing `role`.
+        /// Revert with a standard message if `msg.sender` is missing `role`.
         /// Overriding this function changes the behavior of the {onlyRole} modifier.
         /// Format of the revert message is described in {_checkRole}.
         /// _Available since v4.6._
         fn _check_role(&self, role: [u8; 32]) -> Result<(), Error> {
-            self._check_role(role, msg.sender)?;
+            self._check_role_with_account(role, self.env().caller())?;
             Ok(())
         }
 
-        /// @dev Revert with a standard message if `account` is missing `role`.
+        /// Revert with a standard message if `account` is missing `role`.
         /// The format of the revert reason is given by the following regular expression:
         /// /^AccessControl: account (0x[0-9a-f]{40}) is missing role (0x[0-9a-f]{64})$/
-        fn _check_role(&self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
+        ///Sol2Ink: renamed from the overloaded function `_checkRole`, which changes its selector
+        fn _check_role_with_account(
+            &self,
+            role: [u8; 32],
+            account: AccountId,
+        ) -> Result<(), Error> {
             if !self.has_role(role, account)? {
-                revert(
-                    (abi.encode_packed(
-                        "AccessControl: account ",
-                        strings.to_hex_string(account)?,
-                        " is missing role ",
-                        strings.to_hex_string((role as u128), 32)?,
-                    )? as String),
-                )?;
+                return Err(Error::Custom(format!(
+                    "AccessControl: account {:?} is missing role {:?}",
+                    strings.to_hex_string(account)?,
+                    strings.to_hex_string((role as u128), 32)?
+                )));
             }
             Ok(())
         }
 
-        /// @dev Returns the admin role that controls `role`. See {grantRole} and
+        /// Returns the admin role that controls `role`. See {grantRole} and
         /// {revokeRole}.
         /// To change a role's admin, use {_setRoleAdmin}.
         #[ink(message)]
@@ -198,32 +202,32 @@ pub mod access_control {
             return Ok(self.data.roles.get(&role).unwrap_or_default().admin_role)
         }
 
-        /// @dev Grants `role` to `account`.
+        /// Grants `role` to `account`.
         /// If `account` had not been already granted `role`, emits a {RoleGranted}
         /// event.
         /// Requirements:
         /// - the caller must have ``role``'s admin role.
         /// May emit a {RoleGranted} event.
         #[ink(message)]
-        # [modifiers (only_role (self . get_role_admin (role) ?) ?)]
+        # [modifiers (only_role (self . get_role_admin (role) ?))]
         pub fn grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             self._grant_role(role, account)?;
             Ok(())
         }
 
-        /// @dev Revokes `role` from `account`.
+        /// Revokes `role` from `account`.
         /// If `account` had been granted `role`, emits a {RoleRevoked} event.
         /// Requirements:
         /// - the caller must have ``role``'s admin role.
         /// May emit a {RoleRevoked} event.
         #[ink(message)]
-        # [modifiers (only_role (self . get_role_admin (role) ?) ?)]
+        # [modifiers (only_role (self . get_role_admin (role) ?))]
         pub fn revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             self._revoke_role(role, account)?;
             Ok(())
         }
 
-        /// @dev Revokes `role` from the calling account.
+        /// Revokes `role` from the calling account.
         /// Roles are often managed via {grantRole} and {revokeRole}: this function's
         /// purpose is to provide a mechanism for accounts to lose their privileges
         /// if they are compromised (such as when a trusted device is misplaced).
@@ -243,7 +247,7 @@ pub mod access_control {
             Ok(())
         }
 
-        /// @dev Grants `role` to `account`.
+        /// Grants `role` to `account`.
         /// If `account` had not been already granted `role`, emits a {RoleGranted}
         /// event. Note that unlike {grantRole}, this function doesn't perform any
         /// checks on the calling account.
@@ -261,11 +265,13 @@ pub mod access_control {
             Ok(())
         }
 
-        /// @dev Sets `adminRole` as ``role``'s admin role.
+        /// Sets `adminRole` as ``role``'s admin role.
         /// Emits a {RoleAdminChanged} event.
         fn _set_role_admin(&mut self, role: [u8; 32], admin_role: [u8; 32]) -> Result<(), Error> {
             let previous_admin_role: [u8; 32] = self.get_role_admin(role)?;
-            self.data.roles.get(&role).unwrap_or_default().admin_role = admin_role;
+            let mut roles_entry = self.data.roles.get(&role).unwrap_or_default();
+            roles_entry.admin_role = admin_role;
+            self.data.roles.insert(&role, &(roles_entry));
             self.env().emit_event(RoleAdminChanged {
                 role,
                 previous_admin_role,
@@ -274,18 +280,14 @@ pub mod access_control {
             Ok(())
         }
 
-        /// @dev Grants `role` to `account`.
+        /// Grants `role` to `account`.
         /// Internal function without access restriction.
         /// May emit a {RoleGranted} event.
         fn _grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             if !self.has_role(role, account)? {
-                self.data
-                    .roles
-                    .get(&role)
-                    .unwrap_or_default()
-                    .members
-                    .get(&account)
-                    .unwrap_or_default() = true;
+                let mut roles_entry = self.data.roles.get(&role).unwrap_or_default();
+                roles_entry.members.get(&account).unwrap_or_default() = true;
+                self.data.roles.insert(&role, &(roles_entry));
                 self.env().emit_event(RoleGranted {
                     role,
                     account,
@@ -295,18 +297,14 @@ pub mod access_control {
             Ok(())
         }
 
-        /// @dev Revokes `role` from `account`.
+        /// Revokes `role` from `account`.
         /// Internal function without access restriction.
         /// May emit a {RoleRevoked} event.
         fn _revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error> {
             if self.has_role(role, account)? {
-                self.data
-                    .roles
-                    .get(&role)
-                    .unwrap_or_default()
-                    .members
-                    .get(&account)
-                    .unwrap_or_default() = false;
+                let mut roles_entry = self.data.roles.get(&role).unwrap_or_default();
+                roles_entry.members.get(&account).unwrap_or_default() = false;
+                self.data.roles.insert(&role, &(roles_entry));
                 self.env().emit_event(RoleRevoked {
                     role,
                     account,
@@ -6,7 +6,7 @@
 
 ///SPDX-License-Identifier: MIT
 ///OpenZeppelin Contracts (last updated v4.7.0) (token/ERC1155/ERC1155.sol)
-/// @dev Implementation of the basic standard multi-token.
+/// Implementation of the basic standard multi-token.
 /// See https://eips.ethereum.org/EIPS/eip-1155
 /// Originally based on code by Enjin: https://github.com/enjin/erc-1155
 /// _Available since v3.1._
@@ -37,7 +37,7 @@ pub mod erc_1155 {
     }
 
 
-    /// @dev Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
+    /// Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
     #[ink(event)]
     pub struct TransferSingle {
         #[ink(topic)]
@@ -50,7 +50,7 @@ pub mod erc_1155 {
         value: u128,
     }
 
-    /// @dev Equivalent to multiple {TransferSingle} events, where `operator`, `from` and `to` are the same for all
+    /// Equivalent to multiple {TransferSingle} events, where `operator`, `from` and `to` are the same for all
     /// transfers.
     #[ink(event)]
     pub struct TransferBatch {
@@ -64,7 +64,7 @@ pub mod erc_1155 {
         values: Vec<u128>,
     }
 
-    /// @dev Emitted when `account` grants or revokes permission to `operator` to transfer their tokens, according to
+    /// Emitted when `account` grants or revokes permission to `operator` to transfer their tokens, according to
     /// `approved`.
     #[ink(event)]
     pub struct ApprovalForAll {
@@ -75,7 +75,7 @@ pub mod erc_1155 {
         approved: bool,
     }
 
-    /// @dev Emitted when the URI for token type `id` changes to `value`, if it is a non-programmatic URI.
+    /// Emitted when the URI for token type `id` changes to `value`, if it is a non-programmatic URI.
     /// If an {URI} event was emitted for `id`, the standard
     /// https://eips.ethereum.org/EIPS/eip-1155#metadata-extensions[guarantees] that `value` will equal the value
     /// returned by {IERC1155MetadataURI-uri}.
@@ -107,7 +107,7 @@ pub mod erc_1155 {
     }
 
     impl ERC1155 {
-        /// @dev See {_setURI}.
+        /// See {_setURI}.
         #[ink(constructor)]
         pub fn new(uri: String) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
@@ -115,26 +115,28 @@ pub mod erc_1155 {
             })
         }
 
-        /// @dev See {IERC165-supportsInterface}.
+        /// See {IERC165-supportsInterface}.
         #[ink(message)]
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
-            return Ok(interface_id == ierc_1155.interface_id
-                || interface_id == ierc_1155_metadata_uri.interface_id
+            return Ok(interface_id
+                == todo!("Sol2Ink Not Implemented yet: type(IERC1155).interfaceId")
+                || interface_id
+                    == todo!("Sol2Ink Not Implemented yet: type(IERC1155MetadataURI).interfaceId")
                 || super.supports_interface(interface_id)?)
         }
 
-        /// @dev See {IERC1155MetadataURI-uri}.
+        /// See {IERC1155MetadataURI-uri}.
         /// This implementation returns the same URI for *all* token types. It relies
         /// on the token type ID substitution mechanism
         /// https://eips.ethereum.org/EIPS/eip-1155#metadata[defined in the EIP].
         /// Clients calling this function must replace the `\{id\}` substring with the
         /// actual token type ID.
         #[ink(message)]
-        pub fn uri(&self) -> Result<String, Error> {
-            return Ok(self.data.uri)
+        pub fn uri(&self, arg_0: u128) -> Result<String, Error> {
+            return Ok(self.data.uri.clone())
         }
 
-        /// @dev See {IERC1155-balanceOf}.
+        /// See {IERC1155-balanceOf}.
         /// Requirements:
         /// - `account` cannot be the zero address.
         #[ink(message)]
@@ -147,7 +149,7 @@ pub mod erc_1155 {
             return Ok(self.data.balances.get(&(id, account)).unwrap_or_default())
         }
 
-        /// @dev See {IERC1155-balanceOfBatch}.
+        /// See {IERC1155-balanceOfBatch}.
         /// Requirements:
         /// - `accounts` and `ids` must have the same length.
         #[ink(message)]
@@ -156,27 +158,23 @@ pub mod erc_1155 {
             accounts: Vec<AccountId>,
             ids: Vec<u128>,
         ) -> Result<Vec<u128>, Error> {
-            if accounts.length != ids.length {
+            if (accounts.len() as u128) != (ids.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: accounts and ids length mismatch",
                 )))
             }
-            let batch_balances: Vec<u128> = vec![u128::default(); accounts.length];
-            let i: u128 = 0;
-            while i < accounts.length {
-                batch_balances.insert(
-                    &i,
-                    &(self.balance_of(
-                        accounts.get(&i).unwrap_or_default(),
-                        ids.get(&i).unwrap_or_default(),
-                    )?),
-                );
+            let mut batch_balances: Vec<u128> =
+                vec![u128::default(); (accounts.len() as u128) as usize];
+            let mut i: u128 = 0;
+            while i < (accounts.len() as u128) {
+                batch_balances[i as usize] =
+                    self.balance_of(accounts[i as usize], ids[i as usize])?;
                 i += 1;
             }
             return Ok(batch_balances)
         }
 
-        /// @dev See {IERC1155-setApprovalForAll}.
+        /// See {IERC1155-setApprovalForAll}.
         #[ink(message)]
         pub fn set_approval_for_all(
             &mut self,
@@ -187,7 +185,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev See {IERC1155-isApprovedForAll}.
+        /// See {IERC1155-isApprovedForAll}.
         #[ink(message)]
         pub fn is_approved_for_all(
             &self,
@@ -201,7 +199,7 @@ pub mod erc_1155 {
                 .unwrap_or_default())
         }
 
-        /// @dev See {IERC1155-safeTransferFrom}.
+        /// See {IERC1155-safeTransferFrom}.
         #[ink(message)]
         pub fn safe_transfer_from(
             &mut self,
@@ -211,7 +209,9 @@ pub mod erc_1155 {
             amount: u128,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if from != self.env().caller() || self.is_approved_for_all(from, msg.sender)? {
+            if !(from == self.env().caller()
+                || self.is_approved_for_all(from, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC1155: caller is not token owner nor approved",
                 )))
@@ -220,7 +220,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev See {IERC1155-safeBatchTransferFrom}.
+        /// See {IERC1155-safeBatchTransferFrom}.
         #[ink(message)]
         pub fn safe_batch_transfer_from(
             &mut self,
@@ -230,7 +230,9 @@ pub mod erc_1155 {
             amounts: Vec<u128>,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if from != self.env().caller() || self.is_approved_for_all(from, msg.sender)? {
+            if !(from == self.env().caller()
+                || self.is_approved_for_all(from, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC1155: caller is not token owner nor approved",
                 )))
@@ -239,7 +241,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Transfers `amount` tokens of token type `id` from `from` to `to`.
+        /// Transfers `amount` tokens of token type `id` from `from` to `to`.
         /// Emits a {TransferSingle} event.
         /// Requirements:
         /// - `to` cannot be the zero address.
@@ -290,7 +292,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_safeTransferFrom}.
+        /// xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_safeTransferFrom}.
         /// Emits a {TransferBatch} event.
         /// Requirements:
         /// - If `to` refers to a smart contract, it must implement {IERC1155Receiver-onERC1155BatchReceived} and return the
@@ -303,7 +305,7 @@ pub mod erc_1155 {
             amounts: Vec<u128>,
             data: Vec<u8>,
         ) -> Result<(), Error> {
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
                 )))
@@ -315,10 +317,10 @@ pub mod erc_1155 {
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, from, to, ids, amounts, data)?;
-            let i: u128 = 0;
-            while i < ids.length {
-                let id: u128 = ids.get(&i).unwrap_or_default();
-                let amount: u128 = amounts.get(&i).unwrap_or_default();
+            let mut i: u128 = 0;
+            while i < (ids.len() as u128) {
+                let id: u128 = ids[i as usize];
+                let amount: u128 = amounts[i as usize];
                 let from_balance: u128 = self.data.balances.get(&(id, from)).unwrap_or_default();
                 if from_balance < amount {
                     return Err(Error::Custom(String::from(
@@ -348,7 +350,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Sets a new URI for all token types, by relying on the token type ID
+        /// Sets a new URI for all token types, by relying on the token type ID
         /// substitution mechanism
         /// https://eips.ethereum.org/EIPS/eip-1155#metadata[defined in the EIP].
         /// By this mechanism, any occurrence of the `\{id\}` substring in either the
@@ -366,7 +368,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Creates `amount` tokens of token type `id`, and assigns them to `to`.
+        /// Creates `amount` tokens of token type `id`, and assigns them to `to`.
         /// Emits a {TransferSingle} event.
         /// Requirements:
         /// - `to` cannot be the zero address.
@@ -411,7 +413,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_mint}.
+        /// xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_mint}.
         /// Emits a {TransferBatch} event.
         /// Requirements:
         /// - `ids` and `amounts` must have the same length.
@@ -429,23 +431,23 @@ pub mod erc_1155 {
                     "ERC1155: mint to the zero address",
                 )))
             }
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
                 )))
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, ZERO_ADDRESS.into(), to, ids, amounts, data)?;
-            let i: u128 = 0;
-            while i < ids.length {
+            let mut i: u128 = 0;
+            while i < (ids.len() as u128) {
                 self.data.balances.insert(
-                    &(ids.get(&i).unwrap_or_default(), to),
+                    &(ids[i as usize], to),
                     &(self
                         .data
                         .balances
-                        .get(&(ids.get(&i).unwrap_or_default(), to))
+                        .get(&(ids[i as usize], to))
                         .unwrap_or_default()
-                        + amounts.get(&i).unwrap_or_default()),
+                        + amounts[i as usize]),
                 );
                 i += 1;
             }
@@ -468,7 +470,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Destroys `amount` tokens of token type `id` from `from`
+        /// Destroys `amount` tokens of token type `id` from `from`
         /// Emits a {TransferSingle} event.
         /// Requirements:
         /// - `from` cannot be the zero address.
@@ -505,7 +507,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_burn}.
+        /// xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {_burn}.
         /// Emits a {TransferBatch} event.
         /// Requirements:
         /// - `ids` and `amounts` must have the same length.
@@ -520,17 +522,17 @@ pub mod erc_1155 {
                     "ERC1155: burn from the zero address",
                 )))
             }
-            if ids.length != amounts.length {
+            if (ids.len() as u128) != (amounts.len() as u128) {
                 return Err(Error::Custom(String::from(
                     "ERC1155: ids and amounts length mismatch",
                 )))
             }
             let operator: AccountId = self.env().caller();
             self._before_token_transfer(operator, from, ZERO_ADDRESS.into(), ids, amounts, "")?;
-            let i: u128 = 0;
-            while i < ids.length {
-                let id: u128 = ids.get(&i).unwrap_or_default();
-                let amount: u128 = amounts.get(&i).unwrap_or_default();
+            let mut i: u128 = 0;
+            while i < (ids.len() as u128) {
+                let id: u128 = ids[i as usize];
+                let amount: u128 = amounts[i as usize];
                 let from_balance: u128 = self.data.balances.get(&(id, from)).unwrap_or_default();
                 if from_balance < amount {
                     return Err(Error::Custom(String::from(
@@ -555,7 +557,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Approve `operator` to operate on all of `owner` tokens
+        /// Approve `operator` to operate on all of `owner` tokens
         /// Emits an {ApprovalForAll} event.
         fn _set_approval_for_all(
             &mut self,
@@ -579,7 +581,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Hook that is called before any token transfer. This includes minting
+        /// Hook that is called before any token transfer. This includes minting
         /// and burning, as well as batched variants.
         /// The same hook is called on both single and batched variants. For single
         /// transfers, the length of the `ids` and `amounts` arrays will be 1.
@@ -605,7 +607,7 @@ pub mod erc_1155 {
             Ok(())
         }
 
-        /// @dev Hook that is called after any token transfer. This includes minting
+        /// Hook that is called after any token transfer. This includes minting
         /// and burning, as well as batched variants.
         /// The same hook is called on both single and batched variants. For single
         /// transfers, the length of the `id` and `amount` arrays will be 1.
@@ -645,15 +647,19 @@ pub mod erc_1155 {
                 if true {
                     // try IERC1155Receiver(to).onERC1155Received(operator, from, id, amount, data) returns (bytes4 response) {
                     if response != ierc_1155_receiver.on_erc_1155_received.selector {
-                        revert("ERC1155: ERC1155Receiver rejected tokens")?;
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: ERC1155Receiver rejected tokens",
+                        )));
                     }
                 } else if false {
                     // catch Error(string reason) {
-                    revert(reason)?;
+                    return Err(Error::Custom(reason.to_string()));
                     // <<< Please handle try/catch blocks manually
                 } else if false {
                     // catch {
-                    revert("ERC1155: transfer to non-ERC1155Receiver implementer")?;
+                    return Err(Error::Custom(String::from(
+                        "ERC1155: transfer to non-ERC1155Receiver implementer",
+                    )));
                     // <<< Please handle try/catch blocks manually
                 }
             }
@@ -674,15 +680,19 @@ pub mod erc_1155 {
                 if true {
                     // try IERC1155Receiver(to).onERC1155BatchReceived(operator, from, ids, amounts, data) returns ( bytes4 response ) {
                     if response != ierc_1155_receiver.on_erc_1155_batch_received.selector {
-                        revert("ERC1155: ERC1155Receiver rejected tokens")?;
+                        return Err(Error::Custom(String::from(
+                            "ERC1155: ERC1155Receiver rejected tokens",
+                        )));
                     }
                 } else if false {
                     // catch Error(string reason) {
-                    revert(reason)?;
+                    return Err(Error::Custom(reason.to_string()));
                     // <<< Please handle try/catch blocks manually
                 } else if false {
                     // catch {
-                    revert("ERC1155: transfer to non-ERC1155Receiver implementer")?;
+                    return Err(Error::Custom(String::from(
+                        "ERC1155: transfer to non-ERC1155Receiver implementer",
+                    )));
                     // <<< Please handle try/catch blocks manually
                 }
             }
@@ -690,8 +700,8 @@ pub mod erc_1155 {
         }
 
         fn _as_singleton_array(&self, element: u128) -> Result<Vec<u128>, Error> {
-            let array: Vec<u128> = vec![u128::default(); 1];
-            array.insert(&0, &(element));
+            let mut array: Vec<u128> = vec![u128::default(); 1 as usize];
+            array[0 as usize] = element;
             return Ok(array)
         }
 
@@ -6,7 +6,7 @@
 
 ///SPDX-License-Identifier: MIT
 ///OpenZeppelin Contracts (last updated v4.6.0) (token/ERC20/ERC20.sol)
-/// @dev Implementation of the {IERC20} interface.
+/// Implementation of the {IERC20} interface.
 /// This implementation is agnostic to the way tokens are created. This means
 /// that a supply mechanism has to be added in a derived contract using {_mint}.
 /// For a generic mechanism see {ERC20PresetMinterPauser}.
@@ -48,7 +48,7 @@ pub mod erc_20 {
     }
 
 
-    /// @dev Emitted when `value` tokens are moved from one account (`from`) to
+    /// Emitted when `value` tokens are moved from one account (`from`) to
     /// another (`to`).
     /// Note that `value` may be zero.
     #[ink(event)]
@@ -60,7 +60,7 @@ pub mod erc_20 {
         value: u128,
     }
 
-    /// @dev Emitted when the allowance of a `spender` for an `owner` is set by
+    /// Emitted when the allowance of a `spender` for an `owner` is set by
     /// a call to {approve}. `value` is the new allowance.
     #[ink(event)]
     pub struct Approval {
@@ -73,16 +73,16 @@ pub mod erc_20 {
 
     /// This enum is added just to test enum parsing
     pub enum Enum {
-        First,
-        Second,
+        FIRST,
+        SECOND,
     }
 
     /// This struct is added just to test struct parsing
-    #[derive(Default, Encode, Decode)]
+    #[derive(Default, Clone, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct Struct {
-        field_1: u128,
-        field_2: u128,
+        field1: u128,
+        field2: u128,
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -105,7 +105,7 @@ pub mod erc_20 {
     }
 
     impl ERC20 {
-        /// @dev Sets the values for {name} and {symbol}.
+        /// Sets the values for {name} and {symbol}.
         /// The default value of {decimals} is 18. To select a different value for
         /// {decimals} you should overload it.
         /// All two of these values are immutable: they can only be set once during
@@ -118,20 +118,20 @@ pub mod erc_20 {
             })
         }
 
-        /// @dev Returns the name of the token.
+        /// Returns the name of the token.
         #[ink(message)]
         pub fn name(&self) -> Result<String, Error> {
-            return Ok(self.data.name)
+            return Ok(self.data.name.clone())
         }
 
-        /// @dev Returns the symbol of the token, usually a shorter version of the
+        /// Returns the symbol of the token, usually a shorter version of the
         /// name.
         #[ink(message)]
         pub fn symbol(&self) -> Result<String, Error> {
-            return Ok(self.data.symbol)
+            return Ok(self.data.symbol.clone())
         }
 
-        /// @dev Returns the number of decimals used to get its user representation.
+        /// Returns the number of decimals used to get its user representation.
         /// For example, if `decimals` equals `2`, a balance of `505` tokens should
         /// be displayed to a user as `5.05` (`505 / 10 ** 2`).
         /// Tokens usually opt for a value of 18, imitating the relationship between
@@ -145,19 +145,19 @@ pub mod erc_20 {
             return Ok(18)
         }
 
-        /// @dev See {IERC20-totalSupply}.
+        /// See {IERC20-totalSupply}.
         #[ink(message)]
         pub fn total_supply(&self) -> Result<u128, Error> {
             return Ok(self.data.total_supply)
         }
 
-        /// @dev See {IERC20-balanceOf}.
+        /// See {IERC20-balanceOf}.
         #[ink(message)]
         pub fn balance_of(&self, account: AccountId) -> Result<u128, Error> {
             return Ok(self.data.balances.get(&account).unwrap_or_default())
         }
 
-        /// @dev See {IERC20-transfer}.
+        /// See {IERC20-transfer}.
         /// Requirements:
         /// - `to` cannot be the zero address.
         /// - the caller must have a balance of at least `amount`.
@@ -168,7 +168,7 @@ pub mod erc_20 {
             return Ok(true)
         }
 
-        /// @dev See {IERC20-allowance}.
+        /// See {IERC20-allowance}.
         #[ink(message)]
         pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error> {
             return Ok(self
@@ -178,7 +178,7 @@ pub mod erc_20 {
                 .unwrap_or_default())
         }
 
-        /// @dev See {IERC20-approve}.
+        /// See {IERC20-approve}.
         /// NOTE: If `amount` is the maximum `uint256`, the allowance is not updated on
         /// `transferFrom`. This is semantically equivalent to an infinite approval.
         /// Requirements:
@@ -190,7 +190,7 @@ pub mod erc_20 {
             return Ok(true)
         }
 
-        /// @dev See {IERC20-transferFrom}.
+        /// See {IERC20-transferFrom}.
         /// Emits an {Approval} event indicating the updated allowance. This is not
         /// required by the EIP. See the note at the beginning of {ERC20}.
         /// NOTE: Does not update the allowance if the current allowance
@@ -213,7 +213,7 @@ pub mod erc_20 {
             return Ok(true)
         }
 
-        /// @dev Atomically increases the allowance granted to `spender` by the caller.
+        /// Atomically increases the allowance granted to `spender` by the caller.
         /// This is an alternative to {approve} that can be used as a mitigation for
         /// problems described in {IERC20-approve}.
         /// Emits an {Approval} event indicating the updated allowance.
@@ -234,7 +234,7 @@ pub mod erc_20 {
             return Ok(true)
         }
 
-        /// @dev Atomically decreases the allowance granted to `spender` by the caller.
+        /// Atomically decreases the allowance granted to `spender` by the caller.
         /// This is an alternative to {approve} that can be used as a mitigation for
         /// problems described in {IERC20-approve}.
         /// Emits an {Approval} event indicating the updated allowance.
@@ -261,7 +261,7 @@ pub mod erc_20 {
             return Ok(true)
         }
 
-        /// @dev Moves `amount` of tokens from `from` to `to`.
+        /// Moves `amount` of tokens from `from` to `to`.
         /// This internal function is equivalent to {transfer}, and can be used to
         /// e.g. implement automatic token fees, slashing mechanisms, etc.
         /// Emits a {Transfer} event.
@@ -303,7 +303,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Creates `amount` tokens and assigns them to `account`, increasing
+        /// Creates `amount` tokens and assigns them to `account`, increasing
         /// the total supply.
         /// Emits a {Transfer} event with `from` set to the zero address.
         /// Requirements:
@@ -329,7 +329,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Destroys `amount` tokens from `account`, reducing the
+        /// Destroys `amount` tokens from `account`, reducing the
         /// total supply.
         /// Emits a {Transfer} event with `to` set to the zero address.
         /// Requirements:
@@ -363,7 +363,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Sets `amount` as the allowance of `spender` over the `owner` s tokens.
+        /// Sets `amount` as the allowance of `spender` over the `owner` s tokens.
         /// This internal function is equivalent to `approve`, and can be used to
         /// e.g. set automatic allowances for certain subsystems, etc.
         /// Emits an {Approval} event.
@@ -395,7 +395,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Updates `owner` s allowance for `spender` based on spent `amount`.
+        /// Updates `owner` s allowance for `spender` based on spent `amount`.
         /// Does not update the allowance amount in case of infinite allowance.
         /// Revert if not enough allowance is available.
         /// Might emit an {Approval} event.
@@ -406,7 +406,7 @@ pub mod erc_20 {
             amount: u128,
         ) -> Result<(), Error> {
             let current_allowance: u128 = self.allowance(owner, spender)?;
-            if current_allowance != u128.max {
+            if current_allowance != u128::MAX {
                 if current_allowance < amount {
                     return Err(Error::Custom(String::from("ERC20: insufficient allowance")))
                 }
@@ -417,7 +417,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Hook that is called before any transfer of tokens. This includes
+        /// Hook that is called before any transfer of tokens. This includes
         /// minting and burning.
         /// Calling conditions:
         /// - when `from` and `to` are both non-zero, `amount` of ``from``'s tokens
@@ -435,7 +435,7 @@ pub mod erc_20 {
             Ok(())
         }
 
-        /// @dev Hook that is called after any transfer of tokens. This includes
+        /// Hook that is called after any transfer of tokens. This includes
         /// minting and burning.
         /// Calling conditions:
         /// - when `from` and `to` are both non-zero, `amount` of ``from``'s tokens
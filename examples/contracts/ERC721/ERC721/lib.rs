@@ -6,7 +6,7 @@
 
 ///SPDX-License-Identifier: MIT
 ///OpenZeppelin Contracts (last updated v4.7.0) (token/ERC721/ERC721.sol)
-/// @dev Implementation of https://eips.ethereum.org/EIPS/eip-721[ERC721] Non-Fungible Token Standard, including
+/// Implementation of https://eips.ethereum.org/EIPS/eip-721[ERC721] Non-Fungible Token Standard, including
 /// the Metadata extension, but not including the Enumerable extension, which is available separately as
 /// {ERC721Enumerable}.
 #[openbrush::contract]
@@ -36,7 +36,7 @@ pub mod erc_721 {
     }
 
 
-    /// @dev Emitted when `tokenId` token is transferred from `from` to `to`.
+    /// Emitted when `tokenId` token is transferred from `from` to `to`.
     #[ink(event)]
     pub struct Transfer {
         #[ink(topic)]
@@ -47,7 +47,7 @@ pub mod erc_721 {
         token_id: u128,
     }
 
-    /// @dev Emitted when `owner` enables `approved` to manage the `tokenId` token.
+    /// Emitted when `owner` enables `approved` to manage the `tokenId` token.
     #[ink(event)]
     pub struct Approval {
         #[ink(topic)]
@@ -58,7 +58,7 @@ pub mod erc_721 {
         token_id: u128,
     }
 
-    /// @dev Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
+    /// Emitted when `owner` enables or disables (`approved`) `operator` to manage all of its assets.
     #[ink(event)]
     pub struct ApprovalForAll {
         #[ink(topic)]
@@ -95,7 +95,7 @@ pub mod erc_721 {
     }
 
     impl ERC721 {
-        /// @dev Initializes the contract by setting a `name` and a `symbol` to the token collection.
+        /// Initializes the contract by setting a `name` and a `symbol` to the token collection.
         #[ink(constructor)]
         pub fn new(name: String, symbol: String) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
@@ -104,15 +104,17 @@ pub mod erc_721 {
             })
         }
 
-        /// @dev See {IERC165-supportsInterface}.
+        /// See {IERC165-supportsInterface}.
         #[ink(message)]
         pub fn supports_interface(&self, interface_id: [u8; 4]) -> Result<bool, Error> {
-            return Ok(interface_id == ierc_721.interface_id
-                || interface_id == ierc_721_metadata.interface_id
+            return Ok(interface_id
+                == todo!("Sol2Ink Not Implemented yet: type(IERC721).interfaceId")
+                || interface_id
+                    == todo!("Sol2Ink Not Implemented yet: type(IERC721Metadata).interfaceId")
                 || super.supports_interface(interface_id)?)
         }
 
-        /// @dev See {IERC721-balanceOf}.
+        /// See {IERC721-balanceOf}.
         #[ink(message)]
         pub fn balance_of(&self, owner: AccountId) -> Result<u128, Error> {
             if owner.is_zero() {
@@ -123,7 +125,7 @@ pub mod erc_721 {
             return Ok(self.data.balances.get(&owner).unwrap_or_default())
         }
 
-        /// @dev See {IERC721-ownerOf}.
+        /// See {IERC721-ownerOf}.
         #[ink(message)]
         pub fn owner_of(&self, token_id: u128) -> Result<AccountId, Error> {
             let owner: AccountId = self.data.owners.get(&token_id).unwrap_or_default();
@@ -133,38 +135,38 @@ pub mod erc_721 {
             return Ok(owner)
         }
 
-        /// @dev See {IERC721Metadata-name}.
+        /// See {IERC721Metadata-name}.
         #[ink(message)]
         pub fn name(&self) -> Result<String, Error> {
-            return Ok(self.data.name)
+            return Ok(self.data.name.clone())
         }
 
-        /// @dev See {IERC721Metadata-symbol}.
+        /// See {IERC721Metadata-symbol}.
         #[ink(message)]
         pub fn symbol(&self) -> Result<String, Error> {
-            return Ok(self.data.symbol)
+            return Ok(self.data.symbol.clone())
         }
 
-        /// @dev See {IERC721Metadata-tokenURI}.
+        /// See {IERC721Metadata-tokenURI}.
         #[ink(message)]
         pub fn token_uri(&self, token_id: u128) -> Result<String, Error> {
             self._require_minted(token_id)?;
             let base_uri: String = self._base_uri()?;
-            return Ok(if Vec::<u8>::from(base_uri).length > 0 {
+            return Ok(if (Vec::<u8>::from(base_uri).len() as u128) > 0 {
                 (abi.encode_packed(base_uri, token_id.to_string()?)? as String)
             } else {
                 ""
             })
         }
 
-        /// @dev Base URI for computing {tokenURI}. If set, the resulting URI for each
+        /// Base URI for computing {tokenURI}. If set, the resulting URI for each
         /// token will be the concatenation of the `baseURI` and the `tokenId`. Empty
         /// by default, can be overridden in child contracts.
         fn _base_uri(&self) -> Result<String, Error> {
             return Ok("")
         }
 
-        /// @dev See {IERC721-approve}.
+        /// See {IERC721-approve}.
         #[ink(message)]
         pub fn approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
             let owner: AccountId = erc_721.owner_of(token_id)?;
@@ -173,7 +175,9 @@ pub mod erc_721 {
                     "ERC721: approval to current owner",
                 )))
             }
-            if self.env().caller() != owner || self.is_approved_for_all(owner, msg.sender)? {
+            if !(self.env().caller() == owner
+                || self.is_approved_for_all(owner, self.env().caller())?)
+            {
                 return Err(Error::Custom(String::from(
                     "ERC721: approve caller is not token owner nor approved for all",
                 )))
@@ -182,14 +186,14 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev See {IERC721-getApproved}.
+        /// See {IERC721-getApproved}.
         #[ink(message)]
         pub fn get_approved(&self, token_id: u128) -> Result<AccountId, Error> {
             self._require_minted(token_id)?;
             return Ok(self.data.token_approvals.get(&token_id).unwrap_or_default())
         }
 
-        /// @dev See {IERC721-setApprovalForAll}.
+        /// See {IERC721-setApprovalForAll}.
         #[ink(message)]
         pub fn set_approval_for_all(
             &mut self,
@@ -200,7 +204,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev See {IERC721-isApprovedForAll}.
+        /// See {IERC721-isApprovedForAll}.
         #[ink(message)]
         pub fn is_approved_for_all(
             &self,
@@ -214,7 +218,7 @@ pub mod erc_721 {
                 .unwrap_or_default())
         }
 
-        /// @dev See {IERC721-transferFrom}.
+        /// See {IERC721-transferFrom}.
         #[ink(message)]
         pub fn transfer_from(
             &mut self,
@@ -232,7 +236,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev See {IERC721-safeTransferFrom}.
+        /// See {IERC721-safeTransferFrom}.
         #[ink(message)]
         pub fn safe_transfer_from(
             &mut self,
@@ -240,13 +244,14 @@ pub mod erc_721 {
             to: AccountId,
             token_id: u128,
         ) -> Result<(), Error> {
-            self.safe_transfer_from(from, to, token_id, "")?;
+            self.safe_transfer_from_with_data(from, to, token_id, "")?;
             Ok(())
         }
 
-        /// @dev See {IERC721-safeTransferFrom}.
+        /// See {IERC721-safeTransferFrom}.
+        ///Sol2Ink: renamed from the overloaded function `safeTransferFrom`, which changes its selector
         #[ink(message)]
-        pub fn safe_transfer_from(
+        pub fn safe_transfer_from_with_data(
             &mut self,
             from: AccountId,
             to: AccountId,
@@ -262,7 +267,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Safely transfers `tokenId` token from `from` to `to`, checking first that contract recipients
+        /// Safely transfers `tokenId` token from `from` to `to`, checking first that contract recipients
         /// are aware of the ERC721 protocol to prevent tokens from being forever locked.
         /// `data` is additional data, it has no specified format and it is sent in call to `to`.
         /// This internal function is equivalent to {safeTransferFrom}, and can be used to e.g.
@@ -289,7 +294,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Returns whether `tokenId` exists.
+        /// Returns whether `tokenId` exists.
         /// Tokens can be managed by their owner or approved accounts via {approve} or {setApprovalForAll}.
         /// Tokens start existing when they are minted (`_mint`),
         /// and stop existing when they are burned (`_burn`).
@@ -302,7 +307,7 @@ pub mod erc_721 {
                 .is_zero())
         }
 
-        /// @dev Returns whether `spender` is allowed to manage `tokenId`.
+        /// Returns whether `spender` is allowed to manage `tokenId`.
         /// Requirements:
         /// - `tokenId` must exist.
         fn _is_approved_or_owner(&self, spender: AccountId, token_id: u128) -> Result<bool, Error> {
@@ -312,19 +317,20 @@ pub mod erc_721 {
                 || self.get_approved(token_id)? == spender))
         }
 
-        /// @dev Safely mints `tokenId` and transfers it to `to`.
+        /// Safely mints `tokenId` and transfers it to `to`.
         /// Requirements:
         /// - `tokenId` must not exist.
         /// - If `to` refers to a smart contract, it must implement {IERC721Receiver-onERC721Received}, which is called upon a safe transfer.
         /// Emits a {Transfer} event.
         fn _safe_mint(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
-            self._safe_mint(to, token_id, "")?;
+            self._safe_mint_with_data(to, token_id, "")?;
             Ok(())
         }
 
-        /// @dev Same as {xref-ERC721-_safeMint-address-uint256-}[`_safeMint`], with an additional `data` parameter which is
+        /// Same as {xref-ERC721-_safeMint-address-uint256-}[`_safeMint`], with an additional `data` parameter which is
         /// forwarded in {IERC721Receiver-onERC721Received} to contract recipients.
-        fn _safe_mint(
+        ///Sol2Ink: renamed from the overloaded function `_safeMint`, which changes its selector
+        fn _safe_mint_with_data(
             &mut self,
             to: AccountId,
             token_id: u128,
@@ -339,7 +345,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Mints `tokenId` and transfers it to `to`.
+        /// Mints `tokenId` and transfers it to `to`.
         /// WARNING: Usage of this method is discouraged, use {_safeMint} whenever possible
         /// Requirements:
         /// - `tokenId` must not exist.
@@ -368,7 +374,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Destroys `tokenId`.
+        /// Destroys `tokenId`.
         /// The approval is cleared when the token is burned.
         /// Requirements:
         /// - `tokenId` must exist.
@@ -377,12 +383,12 @@ pub mod erc_721 {
             let owner: AccountId = erc_721.owner_of(token_id)?;
             self._before_token_transfer(owner, ZERO_ADDRESS.into(), token_id)?;
             // Clear approvals
-            // Sol2Ink Not Implemented yet: delete _tokenApprovals[tokenId];
+            self.data.token_approvals.remove(&token_id);
             self.data.balances.insert(
                 &owner,
                 &(self.data.balances.get(&owner).unwrap_or_default() - 1),
             );
-            // Sol2Ink Not Implemented yet: delete _owners[tokenId];
+            self.data.owners.remove(&token_id);
             self.env().emit_event(Transfer {
                 from: owner,
                 to: ZERO_ADDRESS.into(),
@@ -392,7 +398,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Transfers `tokenId` from `from` to `to`.
+        /// Transfers `tokenId` from `from` to `to`.
         /// As opposed to {transferFrom}, this imposes no restrictions on msg.sender.
         /// Requirements:
         /// - `to` cannot be the zero address.
@@ -416,7 +422,7 @@ pub mod erc_721 {
             }
             self._before_token_transfer(from, to, token_id)?;
             // Clear approvals from the previous owner
-            // Sol2Ink Not Implemented yet: delete _tokenApprovals[tokenId];
+            self.data.token_approvals.remove(&token_id);
             self.data.balances.insert(
                 &from,
                 &(self.data.balances.get(&from).unwrap_or_default() - 1),
@@ -430,7 +436,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Approve `to` to operate on `tokenId`
+        /// Approve `to` to operate on `tokenId`
         /// Emits an {Approval} event.
         fn _approve(&mut self, to: AccountId, token_id: u128) -> Result<(), Error> {
             self.data.token_approvals.insert(&token_id, &(to));
@@ -442,7 +448,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Approve `operator` to operate on all of `owner` tokens
+        /// Approve `operator` to operate on all of `owner` tokens
         /// Emits an {ApprovalForAll} event.
         fn _set_approval_for_all(
             &mut self,
@@ -464,7 +470,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Reverts if the `tokenId` has not been minted yet.
+        /// Reverts if the `tokenId` has not been minted yet.
         fn _require_minted(&self, token_id: u128) -> Result<(), Error> {
             if !self._exists(token_id)? {
                 return Err(Error::Custom(String::from("ERC721: invalid token ID")))
@@ -472,13 +478,17 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Internal function to invoke {IERC721Receiver-onERC721Received} on a target address.
+        /// Internal function to invoke {IERC721Receiver-onERC721Received} on a target address.
         /// The call is not executed if the target address is not a contract.
-        /// @param from address representing the previous owner of the given token ID
-        /// @param to target address that will receive the tokens
-        /// @param tokenId uint256 ID of the token to be transferred
-        /// @param data bytes optional data to send along with the call
-        /// @return bool whether the call correctly returned the expected magic value
+        ///
+        /// # Parameters
+        /// - `from`: address representing the previous owner of the given token ID
+        /// - `to`: target address that will receive the tokens
+        /// - `token_id`: uint256 ID of the token to be transferred
+        /// - `data`: bytes optional data to send along with the call
+        ///
+        /// # Returns
+        /// - bool whether the call correctly returned the expected magic value
         fn _check_on_erc_721_received(
             &mut self,
             from: AccountId,
@@ -493,10 +503,12 @@ pub mod erc_721 {
                     return Ok(retval == ierc_721_receiver.on_erc_721_received.selector)
                 } else if false {
                     // catch (bytes reason) {
-                    if reason.length == 0 {
-                        revert("ERC721: transfer to non ERC721Receiver implementer")?;
+                    if (reason.len() as u128) == 0 {
+                        return Err(Error::Custom(String::from(
+                            "ERC721: transfer to non ERC721Receiver implementer",
+                        )));
                     } else {
-                        // @solidity memory-safe-assembly
+                        // @solidity-safe-assembly
                         // Please handle assembly blocks manually >>>
                         // revert(add(32, reason), mload(reason))
                         // <<< Please handle assembly blocks manually
@@ -506,9 +518,10 @@ pub mod erc_721 {
             } else {
                 return Ok(true)
             }
+            Ok(Default::default())
         }
 
-        /// @dev Hook that is called before any token transfer. This includes minting
+        /// Hook that is called before any token transfer. This includes minting
         /// and burning.
         /// Calling conditions:
         /// - When `from` and `to` are both non-zero, ``from``'s `tokenId` will be
@@ -526,7 +539,7 @@ pub mod erc_721 {
             Ok(())
         }
 
-        /// @dev Hook that is called after any transfer of tokens. This includes
+        /// Hook that is called after any transfer of tokens. This includes
         /// minting and burning.
         /// Calling conditions:
         /// - when `from` and `to` are both non-zero.
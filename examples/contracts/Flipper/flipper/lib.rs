@@ -6,6 +6,7 @@
 
 #[openbrush::contract]
 pub mod flipper {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::traits::Storage;
     use scale::{
@@ -49,7 +50,7 @@ pub mod flipper {
         ///to `false` and vice versa.
         #[ink(message)]
         pub fn flip(&mut self) -> Result<(), Error> {
-            self.data.value = !value;
+            self.data.value = !self.data.value;
             Ok(())
         }
 
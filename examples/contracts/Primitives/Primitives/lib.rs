@@ -6,6 +6,7 @@
 
 #[openbrush::contract]
 pub mod primitives {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::traits::Storage;
     use scale::{
@@ -20,18 +21,73 @@ pub mod primitives {
     }
 
 
+    ///Shifts the bits of `bytesN` to the left, as the `<<` operator of Solidity
+    fn shl_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+        let mut output = [0u8; N];
+        let shift = shift.min(8 * N as u128) as usize;
+        let (bytes, bits) = (shift / 8, shift % 8);
+        for i in 0..N - bytes {
+            output[i] = value[i + bytes] << bits;
+            if bits > 0 && i + bytes + 1 < N {
+                output[i] |= value[i + bytes + 1] >> (8 - bits);
+            }
+        }
+        output
+    }
+
+    ///Shifts the bits of `bytesN` to the right, as the `>>` operator of Solidity
+    fn shr_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+        let mut output = [0u8; N];
+        let shift = shift.min(8 * N as u128) as usize;
+        let (bytes, bits) = (shift / 8, shift % 8);
+        for i in bytes..N {
+            output[i] = value[i - bytes] >> bits;
+            if bits > 0 && i > bytes {
+                output[i] |= value[i - bytes - 1] << (8 - bits);
+            }
+        }
+        output
+    }
+
+    ///Applies `&` to each byte of two `bytesN` values
+    fn and_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+        let mut output = [0u8; N];
+        for i in 0..N {
+            output[i] = left[i] & right[i];
+        }
+        output
+    }
+
+    ///Applies `|` to each byte of two `bytesN` values
+    fn or_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+        let mut output = [0u8; N];
+        for i in 0..N {
+            output[i] = left[i] | right[i];
+        }
+        output
+    }
+
+    ///Applies `^` to each byte of two `bytesN` values
+    fn xor_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+        let mut output = [0u8; N];
+        for i in 0..N {
+            output[i] = left[i] ^ right[i];
+        }
+        output
+    }
+
     pub enum Oper {
-        Add,
-        Sub,
-        Mul,
-        Div,
-        Modulo,
-        Pow,
-        Shl,
-        Shr,
-        Or,
-        And,
-        Xor,
+        add,
+        sub,
+        mul,
+        div,
+        modulo,
+        pow,
+        shl,
+        shr,
+        or,
+        and,
+        xor,
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -54,153 +110,153 @@ pub mod primitives {
         }
 
         #[ink(message)]
-        pub fn is_mul(&self, op: oper) -> Result<bool, Error> {
-            return Ok(op == oper.mul)
+        pub fn is_mul(&self, op: Oper) -> Result<bool, Error> {
+            return Ok(op == Oper::mul)
         }
 
         #[ink(message)]
-        pub fn return_div(&self) -> Result<oper, Error> {
-            return Ok(oper.div)
+        pub fn return_div(&self) -> Result<Oper, Error> {
+            return Ok(Oper::div)
         }
 
         #[ink(message)]
-        pub fn op_i_64(&self, op: oper, a: i64, b: i64) -> Result<i64, Error> {
-            if op == oper.add {
+        pub fn op_i64(&self, op: Oper, a: i64, b: i64) -> Result<i64, Error> {
+            if op == Oper::add {
                 return Ok(a + b)
-            } else if op == oper.sub {
+            } else if op == Oper::sub {
                 return Ok(a - b)
-            } else if op == oper.mul {
+            } else if op == Oper::mul {
                 return Ok(a * b)
-            } else if op == oper.div {
+            } else if op == Oper::div {
                 return Ok(a / b)
-            } else if op == oper.modulo {
+            } else if op == Oper::modulo {
                 return Ok(a % b)
-            } else if op == oper.shl {
+            } else if op == Oper::shl {
                 return Ok(a << b)
-            } else if op == oper.shr {
+            } else if op == Oper::shr {
                 return Ok(a >> b)
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_u_64(&self, op: oper, a: u64, b: u64) -> Result<u64, Error> {
-            if op == oper.add {
+        pub fn op_u64(&self, op: Oper, a: u64, b: u64) -> Result<u64, Error> {
+            if op == Oper::add {
                 return Ok(a + b)
-            } else if op == oper.sub {
+            } else if op == Oper::sub {
                 return Ok(a - b)
-            } else if op == oper.mul {
+            } else if op == Oper::mul {
                 return Ok(a * b)
-            } else if op == oper.div {
+            } else if op == Oper::div {
                 return Ok(a / b)
-            } else if op == oper.modulo {
+            } else if op == Oper::modulo {
                 return Ok(a % b)
-            } else if op == oper.pow {
+            } else if op == Oper::pow {
                 return Ok(a.pow(b as u32))
-            } else if op == oper.shl {
+            } else if op == Oper::shl {
                 return Ok(a << b)
-            } else if op == oper.shr {
+            } else if op == Oper::shr {
                 return Ok(a >> b)
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_u_256(&self, op: oper, a: u128, b: u128) -> Result<u128, Error> {
-            if op == oper.add {
+        pub fn op_u256(&self, op: Oper, a: u128, b: u128) -> Result<u128, Error> {
+            if op == Oper::add {
                 return Ok(a + b)
-            } else if op == oper.sub {
+            } else if op == Oper::sub {
                 return Ok(a - b)
-            } else if op == oper.mul {
+            } else if op == Oper::mul {
                 return Ok(a * b)
-            } else if op == oper.div {
+            } else if op == Oper::div {
                 return Ok(a / b)
-            } else if op == oper.modulo {
+            } else if op == Oper::modulo {
                 return Ok(a % b)
-            } else if op == oper.pow {
+            } else if op == Oper::pow {
                 return Ok(a.pow((b as u128) as u32))
-            } else if op == oper.shl {
+            } else if op == Oper::shl {
                 return Ok(a << b)
-            } else if op == oper.shr {
+            } else if op == Oper::shr {
                 return Ok(a >> b)
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_i_256(&self, op: oper, a: i128, b: i128) -> Result<i128, Error> {
-            if op == oper.add {
+        pub fn op_i256(&self, op: Oper, a: i128, b: i128) -> Result<i128, Error> {
+            if op == Oper::add {
                 return Ok(a + b)
-            } else if op == oper.sub {
+            } else if op == Oper::sub {
                 return Ok(a - b)
-            } else if op == oper.mul {
+            } else if op == Oper::mul {
                 return Ok(a * b)
-            } else if op == oper.div {
+            } else if op == Oper::div {
                 return Ok(a / b)
-            } else if op == oper.modulo {
+            } else if op == Oper::modulo {
                 return Ok(a % b)
-            } else if op == oper.shl {
+            } else if op == Oper::shl {
                 return Ok(a << b)
-            } else if op == oper.shr {
+            } else if op == Oper::shr {
                 return Ok(a >> b)
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn return_u_8_6(&self) -> Result<[u8; 6], Error> {
-            return Ok("ABCDEF")
+        pub fn return_u8_6(&self) -> Result<[u8; 6], Error> {
+            return Ok(*b"ABCDEF")
         }
 
         #[ink(message)]
-        pub fn op_u_8_5_shift(&self, op: oper, a: [u8; 5], r: u64) -> Result<[u8; 5], Error> {
-            if op == oper.shl {
-                return Ok(a << r)
-            } else if op == oper.shr {
-                return Ok(a >> r)
+        pub fn op_u8_5_shift(&self, op: Oper, a: [u8; 5], r: u64) -> Result<[u8; 5], Error> {
+            if op == Oper::shl {
+                return Ok(shl_bytes(a, (r as u128)))
+            } else if op == Oper::shr {
+                return Ok(shr_bytes(a, (r as u128)))
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_u_8_5(&self, op: oper, a: [u8; 5], b: [u8; 5]) -> Result<[u8; 5], Error> {
-            if op == oper.or {
-                return Ok(a | b)
-            } else if op == oper.and {
-                return Ok(a & b)
-            } else if op == oper.xor {
-                return Ok(a ^ b)
+        pub fn op_u8_5(&self, op: Oper, a: [u8; 5], b: [u8; 5]) -> Result<[u8; 5], Error> {
+            if op == Oper::or {
+                return Ok(or_bytes(a, b))
+            } else if op == Oper::and {
+                return Ok(and_bytes(a, b))
+            } else if op == Oper::xor {
+                return Ok(xor_bytes(a, b))
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_u_8_14_shift(&self, op: oper, a: [u8; 14], r: u64) -> Result<[u8; 14], Error> {
-            if op == oper.shl {
-                return Ok(a << r)
-            } else if op == oper.shr {
-                return Ok(a >> r)
+        pub fn op_u8_14_shift(&self, op: Oper, a: [u8; 14], r: u64) -> Result<[u8; 14], Error> {
+            if op == Oper::shl {
+                return Ok(shl_bytes(a, (r as u128)))
+            } else if op == Oper::shr {
+                return Ok(shr_bytes(a, (r as u128)))
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
         #[ink(message)]
-        pub fn op_u_8_14(&self, op: oper, a: [u8; 14], b: [u8; 14]) -> Result<[u8; 14], Error> {
-            if op == oper.or {
-                return Ok(a | b)
-            } else if op == oper.and {
-                return Ok(a & b)
-            } else if op == oper.xor {
-                return Ok(a ^ b)
+        pub fn op_u8_14(&self, op: Oper, a: [u8; 14], b: [u8; 14]) -> Result<[u8; 14], Error> {
+            if op == Oper::or {
+                return Ok(or_bytes(a, b))
+            } else if op == Oper::and {
+                return Ok(and_bytes(a, b))
+            } else if op == Oper::xor {
+                return Ok(xor_bytes(a, b))
             } else {
-                revert()?;
+                return Err(Error::Custom(String::new()));
             }
         }
 
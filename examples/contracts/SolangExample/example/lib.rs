@@ -7,6 +7,7 @@
 ///example.sol
 #[openbrush::contract]
 pub mod example {
+    use ink_prelude::string::String;
     use ink_storage::traits::SpreadAllocate;
     use openbrush::traits::{
         AccountIdExt,
@@ -25,9 +26,37 @@ pub mod example {
     }
 
     ///Constants
-    pub const BAD_STATE: State = state.zombie;
+    pub const BAD_STATE: State = State::Zombie;
     pub const FIRST_PID: i32 = 1;
 
+    ///Shifts the bits of `bytesN` to the left, as the `<<` operator of Solidity
+    fn shl_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+        let mut output = [0u8; N];
+        let shift = shift.min(8 * N as u128) as usize;
+        let (bytes, bits) = (shift / 8, shift % 8);
+        for i in 0..N - bytes {
+            output[i] = value[i + bytes] << bits;
+            if bits > 0 && i + bytes + 1 < N {
+                output[i] |= value[i + bytes + 1] >> (8 - bits);
+            }
+        }
+        output
+    }
+
+    ///Shifts the bits of `bytesN` to the right, as the `>>` operator of Solidity
+    fn shr_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+        let mut output = [0u8; N];
+        let shift = shift.min(8 * N as u128) as usize;
+        let (bytes, bits) = (shift / 8, shift % 8);
+        for i in bytes..N {
+            output[i] = value[i - bytes] >> bits;
+            if bits > 0 && i > bytes {
+                output[i] |= value[i - bytes - 1] << (8 - bits);
+            }
+        }
+        output
+    }
+
     ///Process state
     pub enum State {
         Running,
@@ -40,33 +69,33 @@ pub mod example {
 
     ///cards
     pub enum Suit {
-        Club,
-        Diamonds,
-        Hearts,
-        Spades,
+        club,
+        diamonds,
+        hearts,
+        spades,
     }
 
     pub enum Value {
-        Two,
-        Three,
-        Four,
-        Five,
-        Six,
-        Seven,
-        Eight,
-        Nine,
-        Ten,
-        Jack,
-        Queen,
-        King,
-        Ace,
+        two,
+        three,
+        four,
+        five,
+        six,
+        seven,
+        eight,
+        nine,
+        ten,
+        jack,
+        queen,
+        king,
+        ace,
     }
 
-    #[derive(Default, Encode, Decode)]
+    #[derive(Default, Clone, Encode, Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub struct card {
-        v: value,
-        s: suit,
+        v: Value,
+        s: Suit,
     }
 
     pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -78,8 +107,8 @@ pub mod example {
         pub state: State,
         pub pid: i32,
         pub reaped: u32,
-        pub card_1: card,
-        pub card_2: card,
+        pub card1: card,
+        pub card2: card,
     }
 
     #[ink(storage)]
@@ -94,14 +123,14 @@ pub mod example {
         #[ink(constructor)]
         pub fn new(pid: i32) -> Self {
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
-                // Set contract storage
-                instance.data.pid = pid;
-                self.reaped = 3;
-                self.card_1 = card(value.two, suit.club)?;
-                self.card_2 = Card {
-                    s: suit.club,
-                    v: value.two,
+                instance.data.reaped = 3;
+                instance.data.card1 = card(Value::two, Suit::club)?;
+                instance.data.card2 = Card {
+                    s: Suit::club,
+                    v: Value::two,
                 };
+                // Set contract
+                instance.data.pid = pid;
             })
         }
 
@@ -110,7 +139,7 @@ pub mod example {
         #[ink(message)]
         pub fn is_zombie_reaper(&self) -> Result<bool, Error> {
             // must be pid 1 and not zombie ourselves *
-            return Ok((self.data.pid == FIRST_PID && self.data.state != state.zombie))
+            return Ok((self.data.pid == FIRST_PID && self.data.state != State::Zombie))
         }
 
         ///Returning a constant does not access storage at all, so
@@ -124,14 +153,14 @@ pub mod example {
 
         ///Convert celcius to fahrenheit
         #[ink(message)]
-        pub fn celcius_2_fahrenheit(&self, celcius: i32) -> Result<i32, Error> {
+        pub fn celcius2_fahrenheit(&self, celcius: i32) -> Result<i32, Error> {
             let fahrenheit: i32 = celcius * 9 / 5 + 32;
             return Ok(fahrenheit)
         }
 
         ///Convert fahrenheit to celcius
         #[ink(message)]
-        pub fn fahrenheit_2_celcius(&self, fahrenheit: i32) -> Result<i32, Error> {
+        pub fn fahrenheit2_celcius(&self, fahrenheit: i32) -> Result<i32, Error> {
             return Ok((fahrenheit - 32) * 5 / 9)
         }
 
@@ -167,32 +196,31 @@ pub mod example {
 
         ///reverse the bytes in an array of 8 (endian swap)
         #[ink(message)]
-        pub fn byte_8_reverse(&self, input: [u8; 8]) -> Result<[u8; 8], Error> {
+        pub fn byte8_reverse(&self, input: [u8; 8]) -> Result<[u8; 8], Error> {
             let mut out = Default::default();
-            out = ((input << 56) & &hex::decode("ff00_0000_0000_0000"))
-                | ((input << 40) & &hex::decode("00ff_0000_0000_0000"))
-                | ((input << 24) & &hex::decode("0000_ff00_0000_0000"))
-                | ((input << 8) & &hex::decode("0000_00ff_0000_0000"))
-                | ((input >> 8) & &hex::decode("0000_0000_ff00_0000"))
-                | ((input >> 24) & &hex::decode("0000_0000_00ff_0000"))
-                | ((input >> 40) & &hex::decode("0000_0000_0000_ff00"))
-                | ((input >> 56) & &hex::decode("0000_0000_0000_00ff"));
+            out = ((shl_bytes(input, (56 as u128))) & &hex::decode("ff00_0000_0000_0000"))
+                | ((shl_bytes(input, (40 as u128))) & &hex::decode("00ff_0000_0000_0000"))
+                | ((shl_bytes(input, (24 as u128))) & &hex::decode("0000_ff00_0000_0000"))
+                | ((shl_bytes(input, (8 as u128))) & &hex::decode("0000_00ff_0000_0000"))
+                | ((shr_bytes(input, (8 as u128))) & &hex::decode("0000_0000_ff00_0000"))
+                | ((shr_bytes(input, (24 as u128))) & &hex::decode("0000_0000_00ff_0000"))
+                | ((shr_bytes(input, (40 as u128))) & &hex::decode("0000_0000_0000_ff00"))
+                | ((shr_bytes(input, (56 as u128))) & &hex::decode("0000_0000_0000_00ff"));
             Ok(out)
         }
 
         ///This mocks a pid state
-        fn _get_pid_state(&self, pid: u64) -> Result<State, Error> {
-            let n: u64 = 8;
-            let i: u16 = 1;
-            while i < 10 {
+        ///Sol2Ink: renamed from the overloaded function `get_pid_state`, which changes its selector
+        fn _get_pid_state_with_pid(&self, pid: u64) -> Result<State, Error> {
+            let mut n: u64 = 8;
+            for i in 1..10 {
                 if (i % 3) == 0 {
                     n *= pid / (i as u64);
                 } else {
                     n /= 3;
                 }
-                i += 1;
             }
-            return Ok(state(n % (state.state_count as u64))?)
+            return Ok(state(n % (State::StateCount as u64))?)
         }
 
         ///Overloaded function with different return value!
@@ -202,9 +230,9 @@ pub mod example {
 
         #[ink(message)]
         pub fn reap_processes(&mut self) -> Result<(), Error> {
-            let n: u32 = 0;
+            let mut n: u32 = 0;
             while n < 100 {
-                if self._get_pid_state(n)? == state.zombie {
+                if self._get_pid_state_with_pid(n)? == State::Zombie {
                     // reap!
                     self.data.reaped += 1;
                 }
@@ -215,15 +243,15 @@ pub mod example {
 
         #[ink(message)]
         pub fn run_queue(&self) -> Result<u16, Error> {
-            let count: u16 = 0;
+            let mut count: u16 = 0;
             // no initializer means its 0.
             let n: u32 = 0;
             loop {
-                if self._get_pid_state(n)? == state.waiting {
+                if self._get_pid_state_with_pid(n)? == State::Waiting {
                     count += 1;
                 }
-                if n < 1000 {
-                    break
+                if !(n < 1000) {
+                    break;
                 }
             }
             return Ok(count)
@@ -231,10 +259,10 @@ pub mod example {
 
         ///This function does a lot of copying
         #[ink(message)]
-        pub fn set_card_1(&mut self, c: card) -> Result<card, Error> {
+        pub fn set_card1(&mut self, c: card) -> Result<card, Error> {
             let mut previous = Default::default();
-            previous = self.data.card_1;
-            self.data.card_1 = c;
+            previous = self.data.card1;
+            self.data.card1 = c;
             Ok(previous)
         }
 
@@ -242,8 +270,8 @@ pub mod example {
         #[ink(message)]
         pub fn ace_of_spaces(&self) -> Result<card, Error> {
             return Ok(Card {
-                s: suit.spades,
-                v: value.ace,
+                s: Suit::spades,
+                v: Value::ace,
             })
         }
 
@@ -251,17 +279,17 @@ pub mod example {
         #[ink(message)]
         pub fn score_card(&self, c: card) -> Result<u32, Error> {
             let mut score = Default::default();
-            if c.s == suit.hearts {
-                if c.v == value.ace {
+            if c.s == Suit::hearts {
+                if c.v == Value::ace {
                     score = 14;
                 }
-                if c.v == value.king {
+                if c.v == Value::king {
                     score = 13;
                 }
-                if c.v == value.queen {
+                if c.v == Value::queen {
                     score = 12;
                 }
-                if c.v == value.jack {
+                if c.v == Value::jack {
                     score = 11;
                 }
             }
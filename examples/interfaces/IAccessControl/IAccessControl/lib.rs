@@ -1,7 +1,7 @@
 // Generated with Sol2Ink v1.0.0
 // https://github.com/Supercolony-net/sol2ink
 
-/// @dev Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
+/// Emitted when `newAdminRole` is set as ``role``'s admin role, replacing `previousAdminRole`
 /// `DEFAULT_ADMIN_ROLE` is the starting admin for all roles, despite
 /// {RoleAdminChanged} not being emitted signaling this.
 /// _Available since v3.1._
@@ -15,7 +15,7 @@ pub struct RoleAdminChanged {
     new_admin_role: [u8; 32],
 }
 
-/// @dev Emitted when `account` is granted `role`.
+/// Emitted when `account` is granted `role`.
 /// `sender` is the account that originated the contract call, an admin role
 /// bearer except when using {AccessControl-_setupRole}.
 #[ink(event)]
@@ -28,7 +28,7 @@ pub struct RoleGranted {
     sender: AccountId,
 }
 
-/// @dev Emitted when `account` is revoked `role`.
+/// Emitted when `account` is revoked `role`.
 /// `sender` is the account that originated the contract call:
 /// - if using `revokeRole`, it is the admin role bearer
 /// - if using `renounceRole`, it is the role bearer (i.e. `account`)
@@ -47,17 +47,17 @@ pub type AccessControlRef = dyn AccessControl;
 
 #[openbrush::trait_definition]
 pub trait AccessControl {
-    /// @dev Returns `true` if `account` has been granted `role`.
+    /// Returns `true` if `account` has been granted `role`.
     #[ink(message)]
     fn has_role(&self, role: [u8; 32], account: AccountId) -> Result<bool, Error>;
 
-    /// @dev Returns the admin role that controls `role`. See {grantRole} and
+    /// Returns the admin role that controls `role`. See {grantRole} and
     /// {revokeRole}.
     /// To change a role's admin, use {AccessControl-_setRoleAdmin}.
     #[ink(message)]
     fn get_role_admin(&self, role: [u8; 32]) -> Result<[u8; 32], Error>;
 
-    /// @dev Grants `role` to `account`.
+    /// Grants `role` to `account`.
     /// If `account` had not been already granted `role`, emits a {RoleGranted}
     /// event.
     /// Requirements:
@@ -65,14 +65,14 @@ pub trait AccessControl {
     #[ink(message)]
     fn grant_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error>;
 
-    /// @dev Revokes `role` from `account`.
+    /// Revokes `role` from `account`.
     /// If `account` had been granted `role`, emits a {RoleRevoked} event.
     /// Requirements:
     /// - the caller must have ``role``'s admin role.
     #[ink(message)]
     fn revoke_role(&mut self, role: [u8; 32], account: AccountId) -> Result<(), Error>;
 
-    /// @dev Revokes `role` from the calling account.
+    /// Revokes `role` from the calling account.
     /// Roles are often managed via {grantRole} and {revokeRole}: this function's
     /// purpose is to provide a mechanism for accounts to lose their privileges
     /// if they are compromised (such as when a trusted device is misplaced).
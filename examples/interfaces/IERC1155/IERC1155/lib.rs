@@ -5,7 +5,7 @@ use ink_prelude::{
     string::String,
     vec::Vec,
 };
-/// @dev Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
+/// Emitted when `value` tokens of token type `id` are transferred from `from` to `to` by `operator`.
 #[ink(event)]
 pub struct TransferSingle {
     #[ink(topic)]
@@ -18,7 +18,7 @@ pub struct TransferSingle {
     value: u128,
 }
 
-/// @dev Equivalent to multiple {TransferSingle} events, where `operator`, `from` and `to` are the same for all
+/// Equivalent to multiple {TransferSingle} events, where `operator`, `from` and `to` are the same for all
 /// transfers.
 #[ink(event)]
 pub struct TransferBatch {
@@ -32,7 +32,7 @@ pub struct TransferBatch {
     values: Vec<u128>,
 }
 
-/// @dev Emitted when `account` grants or revokes permission to `operator` to transfer their tokens, according to
+/// Emitted when `account` grants or revokes permission to `operator` to transfer their tokens, according to
 /// `approved`.
 #[ink(event)]
 pub struct ApprovalForAll {
@@ -43,7 +43,7 @@ pub struct ApprovalForAll {
     approved: bool,
 }
 
-/// @dev Emitted when the URI for token type `id` changes to `value`, if it is a non-programmatic URI.
+/// Emitted when the URI for token type `id` changes to `value`, if it is a non-programmatic URI.
 /// If an {URI} event was emitted for `id`, the standard
 /// https://eips.ethereum.org/EIPS/eip-1155#metadata-extensions[guarantees] that `value` will equal the value
 /// returned by {IERC1155MetadataURI-uri}.
@@ -59,13 +59,13 @@ pub type ERC1155Ref = dyn ERC1155;
 
 #[openbrush::trait_definition]
 pub trait ERC1155 {
-    /// @dev Returns the amount of tokens of token type `id` owned by `account`.
+    /// Returns the amount of tokens of token type `id` owned by `account`.
     /// Requirements:
     /// - `account` cannot be the zero address.
     #[ink(message)]
     fn balance_of(&self, account: AccountId, id: u128) -> Result<u128, Error>;
 
-    /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {balanceOf}.
+    /// xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {balanceOf}.
     /// Requirements:
     /// - `accounts` and `ids` must have the same length.
     #[ink(message)]
@@ -75,19 +75,19 @@ pub trait ERC1155 {
         ids: Vec<u128>,
     ) -> Result<Vec<u128>, Error>;
 
-    /// @dev Grants or revokes permission to `operator` to transfer the caller's tokens, according to `approved`,
+    /// Grants or revokes permission to `operator` to transfer the caller's tokens, according to `approved`,
     /// Emits an {ApprovalForAll} event.
     /// Requirements:
     /// - `operator` cannot be the caller.
     #[ink(message)]
     fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<(), Error>;
 
-    /// @dev Returns true if `operator` is approved to transfer ``account``'s tokens.
+    /// Returns true if `operator` is approved to transfer ``account``'s tokens.
     /// See {setApprovalForAll}.
     #[ink(message)]
     fn is_approved_for_all(&self, account: AccountId, operator: AccountId) -> Result<bool, Error>;
 
-    /// @dev Transfers `amount` tokens of token type `id` from `from` to `to`.
+    /// Transfers `amount` tokens of token type `id` from `from` to `to`.
     /// Emits a {TransferSingle} event.
     /// Requirements:
     /// - `to` cannot be the zero address.
@@ -105,7 +105,7 @@ pub trait ERC1155 {
         data: Vec<u8>,
     ) -> Result<(), Error>;
 
-    /// @dev xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {safeTransferFrom}.
+    /// xref:ROOT:erc1155.adoc#batch-operations[Batched] version of {safeTransferFrom}.
     /// Emits a {TransferBatch} event.
     /// Requirements:
     /// - `ids` and `amounts` must have the same length.
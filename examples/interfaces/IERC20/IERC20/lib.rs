@@ -1,7 +1,7 @@
 // Generated with Sol2Ink v1.0.0
 // https://github.com/Supercolony-net/sol2ink
 
-/// @dev Emitted when `value` tokens are moved from one account (`from`) to
+/// Emitted when `value` tokens are moved from one account (`from`) to
 /// another (`to`).
 /// Note that `value` may be zero.
 #[ink(event)]
@@ -13,7 +13,7 @@ pub struct Transfer {
     value: u128,
 }
 
-/// @dev Emitted when the allowance of a `spender` for an `owner` is set by
+/// Emitted when the allowance of a `spender` for an `owner` is set by
 /// a call to {approve}. `value` is the new allowance.
 #[ink(event)]
 pub struct Approval {
@@ -29,28 +29,28 @@ pub type ERC20Ref = dyn ERC20;
 
 #[openbrush::trait_definition]
 pub trait ERC20 {
-    /// @dev Returns the amount of tokens in existence.
+    /// Returns the amount of tokens in existence.
     #[ink(message)]
     fn total_supply(&self) -> Result<u128, Error>;
 
-    /// @dev Returns the amount of tokens owned by `account`.
+    /// Returns the amount of tokens owned by `account`.
     #[ink(message)]
     fn balance_of(&self, account: AccountId) -> Result<u128, Error>;
 
-    /// @dev Moves `amount` tokens from the caller's account to `to`.
+    /// Moves `amount` tokens from the caller's account to `to`.
     /// Returns a boolean value indicating whether the operation succeeded.
     /// Emits a {Transfer} event.
     #[ink(message)]
     fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error>;
 
-    /// @dev Returns the remaining number of tokens that `spender` will be
+    /// Returns the remaining number of tokens that `spender` will be
     /// allowed to spend on behalf of `owner` through {transferFrom}. This is
     /// zero by default.
     /// This value changes when {approve} or {transferFrom} are called.
     #[ink(message)]
     fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error>;
 
-    /// @dev Sets `amount` as the allowance of `spender` over the caller's tokens.
+    /// Sets `amount` as the allowance of `spender` over the caller's tokens.
     /// Returns a boolean value indicating whether the operation succeeded.
     /// IMPORTANT: Beware that changing an allowance with this method brings the risk
     /// that someone may use both the old and the new allowance by unfortunate
@@ -62,7 +62,7 @@ pub trait ERC20 {
     #[ink(message)]
     fn approve(&mut self, spender: AccountId, amount: u128) -> Result<bool, Error>;
 
-    /// @dev Moves `amount` tokens from `from` to `to` using the
+    /// Moves `amount` tokens from `from` to `to` using the
     /// allowance mechanism. `amount` is then deducted from the caller's
     /// allowance.
     /// Returns a boolean value indicating whether the operation succeeded.
@@ -21,63 +21,72 @@
 // SOFTWARE.
 
 use std::{
-    collections::HashSet,
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
     str::FromStr,
 };
 
-use crate::structures::*;
+use crate::{
+    formatter::snake_case,
+    structures::*,
+};
 use convert_case::{
     Case::{
         Pascal,
-        Snake,
         UpperSnake,
     },
     Casing,
 };
 use proc_macro2::{
+    Group,
     Ident,
     TokenStream,
+    TokenTree,
 };
 use quote::*;
 
 /// Assembles ink! contract from the parsed contract struct and return it as a vec of Strings
-pub fn assemble_contract(contract: Contract) -> TokenStream {
-    let mod_name = format_ident!("{}", contract.name.to_case(Snake));
+///
+/// `namespace` the crate of the OpenBrush macros and imports
+pub fn assemble_contract(contract: Contract, namespace: &str) -> TokenStream {
+    let mod_name = format_ident!("{}", snake_case(&contract.name));
     let contract_name = format_ident!("{}", contract.name);
+    let namespace = format_ident!("{}", namespace);
     let signature = signature();
-    let imports = assemble_imports(contract.imports);
+    let imports = assemble_imports(contract.imports, &namespace);
     let events = assemble_events(contract.events);
     let enums = assemble_enums(contract.enums);
     let structs = assemble_structs(contract.structs);
-    let storage = assemble_storage(&contract.name, &contract.fields);
+    let bases = contract
+        .base_contracts
+        .iter()
+        .filter_map(|base| OpenBrushBase::from_name(base))
+        .collect::<Vec<_>>();
+    let errors = assemble_errors(&contract.errors, &bases);
+    let storage = assemble_storage(&contract.name, &contract.fields, &bases, &namespace);
     let constructor = assemble_constructor(contract.constructor, &contract.fields);
     let constants = assemble_constants(contract.fields);
     let functions = assemble_functions(contract.functions);
     let comments = assemble_contract_doc(contract.contract_doc);
     let modifiers = assemble_modifiers(contract.modifiers, &contract_name);
+    let helpers = assemble_helpers(contract.helpers);
 
     let contract = quote! {
-        #![cfg_attr(not(feature = "std"), no_std)]
-        #![feature(min_specialization)]
-        _blank_!();
         #signature
         #comments
-        #[openbrush::contract]
+        #[#namespace::contract]
         pub mod #mod_name {
             #imports
             use scale::Encode;
             use scale::Decode;
             use ink_storage::traits::SpreadAllocate;
-            use openbrush::traits::Storage;
+            use #namespace::traits::Storage;
             _blank_!();
-            #[derive(Debug, Encode, Decode, PartialEq)]
-            #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-            pub enum Error {
-                Custom(String),
-            }
-            _blank_!();
-
+            #errors
             #constants
+            #helpers
             #modifiers
             #events
             #enums
@@ -93,12 +102,26 @@ pub fn assemble_contract(contract: Contract) -> TokenStream {
     contract
 }
 
+/// Adds the crate level attributes to the assembled contract,
+/// so it can be used as `lib.rs` of a standalone crate
+pub fn assemble_lib(output: TokenStream) -> TokenStream {
+    quote! {
+        #![cfg_attr(not(feature = "std"), no_std)]
+        #![feature(min_specialization)]
+        _blank_!();
+        #output
+    }
+}
+
 /// Assembles ink! interface(trait) from the parsed interface struct and return it as a vec of Strings
-pub fn assemble_interface(interface: Interface) -> TokenStream {
+///
+/// `namespace` the crate of the OpenBrush macros and imports
+pub fn assemble_interface(interface: Interface, namespace: &str) -> TokenStream {
     let interface_name = TokenStream::from_str(&interface.name).unwrap();
     let interface_name_ref = TokenStream::from_str(&format!("{}Ref", interface.name)).unwrap();
+    let namespace = format_ident!("{}", namespace);
     let signature = signature();
-    let imports = assemble_imports(interface.imports);
+    let imports = assemble_imports(interface.imports, &namespace);
     let events = assemble_events(interface.events);
     let enums = assemble_enums(interface.enums);
     let structs = assemble_structs(interface.structs);
@@ -110,10 +133,10 @@ pub fn assemble_interface(interface: Interface) -> TokenStream {
         #events
         #enums
         #structs
-        #[openbrush::wrapper]
+        #[#namespace::wrapper]
         pub type #interface_name_ref = dyn #interface_name;
         _blank_!();
-        #[openbrush::trait_definition]
+        #[#namespace::trait_definition]
         pub trait #interface_name {
             #function_headers
         }
@@ -136,11 +159,17 @@ fn assemble_contract_doc(comments: Vec<String>) -> TokenStream {
 }
 
 /// Sorts the imports inside the HashSet and return it as a Vec of Strings
-fn assemble_imports(imports: HashSet<String>) -> TokenStream {
+///
+/// the imports of OpenBrush are taken from the `namespace` crate
+fn assemble_imports(imports: HashSet<String>, namespace: &Ident) -> TokenStream {
     let mut output = TokenStream::new();
     let output_vec = Vec::from_iter(imports);
 
     for import in output_vec {
+        let import = match import.strip_prefix("use openbrush::") {
+            Some(path) => format!("use {namespace}::{path}"),
+            None => import,
+        };
         output.extend(TokenStream::from_str(&import).unwrap());
     }
 
@@ -165,10 +194,24 @@ fn assemble_enums(enums: Vec<Enum>) -> TokenStream {
 
         // assemble enum values
         for value in enumeration.values.iter() {
-            let value_name = TokenStream::from_str(&value.to_case(Pascal)).unwrap();
+            let value_name = TokenStream::from_str(&value.name).unwrap();
 
-            values.extend(quote! {
-                #value_name,
+            values.extend(match &value.discriminant {
+                Some(discriminant) => {
+                    let discriminant = TokenStream::from_str(discriminant).unwrap();
+                    quote! {
+                        #value_name = #discriminant,
+                    }
+                }
+                None => quote! { #value_name, },
+            });
+        }
+
+        // explicit discriminants need the representation of the enum to be fixed
+        if let Some(repr) = enum_repr(&enumeration.values) {
+            let repr = TokenStream::from_str(repr).unwrap();
+            enum_comments.extend(quote! {
+                #[repr(#repr)]
             });
         }
 
@@ -184,6 +227,48 @@ fn assemble_enums(enums: Vec<Enum>) -> TokenStream {
     output
 }
 
+/// Returns the smallest representation which fits all discriminants of the enum,
+/// or `None` if the enum has no explicit discriminants or they can not be evaluated
+///
+/// `values` the values of the enum
+fn enum_repr(values: &[EnumValue]) -> Option<&'static str> {
+    if values.iter().all(|value| value.discriminant.is_none()) {
+        return None
+    }
+
+    // the values without a discriminant follow the previous value
+    let mut next = 0u128;
+    let mut largest = 0u128;
+    for value in values.iter() {
+        let current = match &value.discriminant {
+            Some(discriminant) => {
+                let discriminant = discriminant.replace('_', "");
+                match discriminant.strip_prefix("0x") {
+                    Some(hex) => u128::from_str_radix(hex, 16).ok()?,
+                    None => discriminant.parse::<u128>().ok()?,
+                }
+            }
+            None => next,
+        };
+        largest = largest.max(current);
+        next = current.saturating_add(1);
+    }
+
+    Some(
+        if largest <= u8::MAX as u128 {
+            "u8"
+        } else if largest <= u16::MAX as u128 {
+            "u16"
+        } else if largest <= u32::MAX as u128 {
+            "u32"
+        } else if largest <= u64::MAX as u128 {
+            "u64"
+        } else {
+            "u128"
+        },
+    )
+}
+
 /// Assembles ink! events from the vec of parsed Event structs and return them as a vec of Strings
 fn assemble_events(events: Vec<Event>) -> TokenStream {
     let mut output = TokenStream::new();
@@ -208,7 +293,7 @@ fn assemble_events(events: Vec<Event>) -> TokenStream {
                 });
             }
 
-            let event_field_name = format_ident!("{}", event_field.name.to_case(Snake));
+            let event_field_name = format_ident!("{}", snake_case(&event_field.name));
             let event_field_type = TokenStream::from_str(&event_field.field_type).unwrap();
 
             event_fields.extend(quote! {
@@ -231,14 +316,42 @@ fn assemble_events(events: Vec<Event>) -> TokenStream {
 }
 
 /// Assembles ink! storage struct from the vec of parsed ContractField structs and return it as a vec of Strings
-fn assemble_storage(contract_name: &String, fields: &[ContractField]) -> TokenStream {
+fn assemble_storage(
+    contract_name: &String,
+    fields: &[ContractField],
+    bases: &[OpenBrushBase],
+    namespace: &Ident,
+) -> TokenStream {
     let mut output = TokenStream::new();
     let contract_name = format_ident!("{}", contract_name);
     let mut storage_fields = TokenStream::new();
+    let mut base_fields = TokenStream::new();
+    let mut base_impls = TokenStream::new();
+
+    for base in bases.iter() {
+        let field_name = format_ident!("{}", base.storage_field());
+        let module = format_ident!("{}", base.module());
+        let trait_name = format_ident!("{}", base.trait_name());
+        let error = format_ident!("{}", base.error());
+        base_fields.extend(quote! {
+            #[storage_field]
+            #field_name: #module::Data,
+        });
+        base_impls.extend(quote! {
+            impl #trait_name for #contract_name {}
+            _blank_!();
+            impl From<#error> for Error {
+                fn from(error: #error) -> Self {
+                    Error::#error(error)
+                }
+            }
+            _blank_!();
+        });
+    }
 
     // assemble storage fields
     for field in fields.iter().filter(|field| !field.constant) {
-        let field_name = format_ident!("{}", field.name.to_case(Snake));
+        let field_name = format_ident!("{}", snake_case(&field.name));
         let field_type = TokenStream::from_str(&field.field_type).unwrap();
 
         for comment in field.comments.iter() {
@@ -252,10 +365,10 @@ fn assemble_storage(contract_name: &String, fields: &[ContractField]) -> TokenSt
     }
 
     output.extend(quote! {
-        pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+        pub const STORAGE_KEY: u32 = #namespace::storage_unique_key!(Data);
         _blank_!();
         #[derive(Default, Debug)]
-        #[openbrush::upgradeable_storage(STORAGE_KEY)]
+        #[#namespace::upgradeable_storage(STORAGE_KEY)]
         pub struct Data {
             #storage_fields
         }
@@ -265,13 +378,75 @@ fn assemble_storage(contract_name: &String, fields: &[ContractField]) -> TokenSt
         pub struct #contract_name {
             #[storage_field]
             data: Data,
+            #base_fields
         }
         _blank_!();
+        #base_impls
     });
 
     output
 }
 
+/// Assembles the `Error` enum returned by the messages of the contract
+///
+/// `Custom(String)` is always present, as reverts and requires with a message return it,
+/// followed by the custom errors of the contract and the errors of its OpenBrush bases
+fn assemble_errors(errors: &[CustomError], bases: &[OpenBrushBase]) -> TokenStream {
+    let custom_errors = assemble_custom_errors(errors);
+    let base_errors = assemble_base_errors(bases);
+
+    quote! {
+        #[derive(Debug, Encode, Decode, PartialEq)]
+        #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+        pub enum Error {
+            Custom(String),
+            #(#custom_errors)*
+            #(#base_errors)*
+        }
+        _blank_!();
+    }
+}
+
+/// Assembles the variants of the `Error` enum wrapping the errors of OpenBrush base contracts
+fn assemble_base_errors(bases: &[OpenBrushBase]) -> Vec<TokenStream> {
+    bases
+        .iter()
+        .map(|base| {
+            let error = format_ident!("{}", base.error());
+            quote!(#error(#error),)
+        })
+        .collect()
+}
+
+/// Assembles the variants of the `Error` enum for the custom errors declared in the contract
+///
+/// errors without parameters are assembled as unit variants
+fn assemble_custom_errors(errors: &[CustomError]) -> Vec<TokenStream> {
+    errors
+        .iter()
+        .map(|error| {
+            let error_name = format_ident!("{}", error.name);
+            let comments = &error.comments;
+            let fields = error
+                .fields
+                .iter()
+                .map(|field| TokenStream::from_str(field).unwrap())
+                .collect::<Vec<_>>();
+            if fields.is_empty() {
+                quote! {
+                    #(#[doc = #comments])*
+                    #error_name,
+                }
+            } else {
+                quote! {
+                    #(#[doc = #comments])*
+                    #error_name(#(#fields),*),
+                }
+            }
+        })
+        .collect()
+}
+
 /// Assembles constant fields of the contract
 fn assemble_constants(fields: Vec<ContractField>) -> TokenStream {
     let mut output = TokenStream::new();
@@ -317,7 +492,7 @@ fn assemble_structs(structs: Vec<Struct>) -> TokenStream {
 
         // assemble struct fields
         for struct_field in structure.fields.iter() {
-            let struct_field_name = format_ident!("{}", struct_field.name.to_case(Snake));
+            let struct_field_name = format_ident!("{}", snake_case(&struct_field.name));
             let struct_field_type = TokenStream::from_str(&struct_field.field_type).unwrap();
 
             struct_fields.extend(quote! {
@@ -327,7 +502,7 @@ fn assemble_structs(structs: Vec<Struct>) -> TokenStream {
 
         output.extend(quote! {
             #struct_comments
-            #[derive(Default, Encode, Decode)]
+            #[derive(Default, Clone, Encode, Decode)]
             #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
             pub struct #struct_name {
                 #struct_fields
@@ -358,7 +533,7 @@ fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> Toke
 
     // assemble params
     for param in constructor.header.params.iter() {
-        let param_name = format_ident!("{}", param.name.to_case(Snake));
+        let param_name = format_ident!("{}", snake_case(&param.name));
         let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
         params.extend(quote! {
@@ -368,26 +543,33 @@ fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> Toke
 
     let mut body = TokenStream::new();
 
-    // assemble body
-    body.extend(quote! {
-        #(#constructor_functions)*
-    });
-
+    // initial values of fields are assigned before the constructor body
     for field in fields
         .iter()
         .filter(|field| field.initial_value.is_some() && !field.constant)
     {
-        let field_name = format_ident!("{}", field.name.to_case(Snake));
+        let field_name = format_ident!("{}", snake_case(&field.name));
         let intial_value = field.initial_value.clone();
 
         body.extend(quote! {
-            self.#field_name = #intial_value;
+            instance.data.#field_name = #intial_value;
         });
     }
 
+    // assemble body
+    body.extend(quote! {
+        #(#constructor_functions)*
+    });
+
+    let attribute = if constructor.header.payable {
+        quote!(#[ink(constructor, payable)])
+    } else {
+        quote!(#[ink(constructor)])
+    };
+
     output.extend(quote! {
         #comments
-        #[ink(constructor)]
+        #attribute
         pub fn new(#params) -> Self{
             ink_lang::codegen::initialize_contract(|instance: &mut Self| {
                 #body
@@ -399,6 +581,29 @@ fn assemble_constructor(constructor: Function, fields: &[ContractField]) -> Toke
     output
 }
 
+/// Returns true if the statements end with a return or a revert in all of their branches
+///
+/// `statements` the statements of a function body
+fn diverges(statements: &[Statement]) -> bool {
+    match statements.split_last() {
+        Some((Statement::Return(_) | Statement::Revert(..), _)) => true,
+        Some((Statement::Group(inner), _)) => diverges(inner),
+        Some((Statement::Else(inner), mut previous)) => {
+            if !diverges(inner) {
+                return false
+            }
+            while let Some((Statement::ElseIf(_, inner), rest)) = previous.split_last() {
+                if !diverges(inner) {
+                    return false
+                }
+                previous = rest;
+            }
+            matches!(previous.last(), Some(Statement::If(_, inner)) if diverges(inner))
+        }
+        _ => false,
+    }
+}
+
 /// Assembles ink! functions from the vec of parsed Function structs and return them as a vec of Strings
 fn assemble_functions(functions: Vec<Function>) -> TokenStream {
     let mut output = TokenStream::new();
@@ -422,8 +627,16 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
         }
 
         for function_modifier in function.header.modifiers.iter() {
+            // modifiers are applied by OpenBrush, so we do not propagate their errors
+            let modifier = match function_modifier {
+                Expression::FunctionCall(modifier_name_raw, args, ..) => {
+                    let modifier_name = format_ident!("{}", snake_case(modifier_name_raw));
+                    quote!(#modifier_name(#(#args),*))
+                }
+                modifier => quote!(#modifier),
+            };
             function_modifiers.extend(quote! {
-                #[modifiers(#function_modifier)]
+                #[modifiers(#modifier)]
             });
         }
 
@@ -449,7 +662,7 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
                 } else {
                     String::from("fn _")
                 },
-                function.header.name.to_case(Snake)
+                snake_case(&function.header.name)
             ))
             .unwrap(),
         );
@@ -465,7 +678,7 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
 
         // assemble params
         for param in function.header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", snake_case(&param.name));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -490,7 +703,7 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
                 });
 
                 if param.name != "_" {
-                    let param_name = TokenStream::from_str(&param.name.to_case(Snake)).unwrap();
+                    let param_name = TokenStream::from_str(&snake_case(&param.name)).unwrap();
                     body.extend(quote! {
                         let mut #param_name = Default::default();
                     })
@@ -517,22 +730,23 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
             #(#statements)*
         });
 
-        if function.header.return_params.is_empty() {
-            body.extend(quote! {
-                Ok(())
-            });
-        } else if function.header.return_params[0].name != "_" {
-            let out = TokenStream::from_str(
-                &function
-                    .header
-                    .return_params
-                    .iter()
-                    .map(|param| param.name.clone())
-                    .collect::<Vec<String>>()
-                    .join(","),
-            )
-            .unwrap();
-            if !statements.iter().any(|s| matches!(s, Statement::Return(_))) {
+        // a body ending with a return or a revert needs no fallback
+        if !diverges(statements) {
+            if function.header.return_params.is_empty() {
+                body.extend(quote! {
+                    Ok(())
+                });
+            } else if function.header.return_params[0].name != "_" {
+                let out = TokenStream::from_str(
+                    &function
+                        .header
+                        .return_params
+                        .iter()
+                        .map(|param| snake_case(&param.name))
+                        .collect::<Vec<String>>()
+                        .join(","),
+                )
+                .unwrap();
                 body.extend(
                     if function.header.return_params.len() > 1 {
                         quote! {
@@ -544,6 +758,15 @@ fn assemble_functions(functions: Vec<Function>) -> TokenStream {
                         }
                     },
                 );
+            } else if statements.is_empty() || function.untranslated {
+                body.extend(quote! {
+                    todo!()
+                });
+            } else {
+                // Solidity returns the default values when the function ends without a return
+                body.extend(quote! {
+                    Ok(Default::default())
+                });
             }
         }
 
@@ -569,7 +792,7 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
     let mut output = TokenStream::new();
 
     for modifier in modifiers.iter() {
-        let modifier_name = format_ident!("{}", modifier.header.name.to_case(Snake));
+        let modifier_name = format_ident!("{}", snake_case(&modifier.header.name));
         let mut body = TokenStream::new();
         let mut comments = TokenStream::new();
         let mut params = TokenStream::new();
@@ -584,7 +807,7 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
 
         // assemble params
         for param in modifier.header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", snake_case(&param.name));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -592,20 +815,35 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
             });
         }
 
-        // body
-        body.extend(quote! {
-            #(#statements)*
-        });
+        // body, the result of the modified function is returned after the statements following `_;`
+        let mut bounds = quote!(F: FnOnce(&mut #contract_name) -> Result<R, Error>);
+        if let Some((Statement::ModifierBody, statements)) = statements.split_last() {
+            body.extend(quote! {
+                #(#statements)*
+                body(instance)
+            });
+        } else if statements.contains(&Statement::ModifierBody) {
+            body.extend(quote! {
+                #(#statements)*
+                Ok(result)
+            });
+        } else {
+            // without `_;` the modified function is skipped and returns the default values
+            bounds.extend(quote!(, R: Default));
+            body.extend(quote! {
+                #(#statements)*
+                Ok(Default::default())
+            });
+        }
+        // the modifier is a function outside of the contract, which receives the contract as `instance`
+        let body = replace_self(body);
 
         output.extend(quote! {
             #comments
-            #[doc = "The type of `T` should be the trait which implements the storage"]
-            #[doc = "This will be implemented in Sol2Ink in upcoming version"]
             #[modifier_definition]
-            pub fn #modifier_name<T, F, R>(instance: &mut T, body: F #params) -> Result<R, Error>
+            pub fn #modifier_name<F, R>(instance: &mut #contract_name, body: F #params) -> Result<R, Error>
             where
-                T: #contract_name,
-                F: FnOnce(&mut T) -> Result<R, Error>
+                #bounds
             {
                 #body
             }
@@ -619,6 +857,131 @@ fn assemble_modifiers(modifiers: Vec<Modifier>, contract_name: &Ident) -> TokenS
     output
 }
 
+/// Replaces `self` with `instance` in the tokens, including the nested groups
+fn replace_self(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| {
+            match token {
+                TokenTree::Ident(ident) if ident == "self" => {
+                    TokenTree::Ident(Ident::new("instance", ident.span()))
+                }
+                TokenTree::Group(group) => {
+                    let mut replaced = Group::new(group.delimiter(), replace_self(group.stream()));
+                    replaced.set_span(group.span());
+                    TokenTree::Group(replaced)
+                }
+                token => token,
+            }
+        })
+        .collect()
+}
+
+/// Assembles the helper functions used by the contract
+fn assemble_helpers(helpers: BTreeSet<Helper>) -> TokenStream {
+    let mut output = TokenStream::new();
+
+    for helper in helpers.iter() {
+        output.extend(match helper {
+            Helper::Bytes32ToString => {
+                quote! {
+                    #[doc = "Converts `bytes32` to `String`, trailing zero bytes are trimmed"]
+                    fn bytes32_to_string(value: [u8; 32]) -> String {
+                        let length = value.iter().rposition(|byte| *byte != 0).map_or(0, |index| index + 1);
+                        String::from_utf8_lossy(&value[..length]).into_owned()
+                    }
+                }
+            }
+            Helper::StringToBytes32 => {
+                quote! {
+                    #[doc = "Converts `String` to `bytes32`, the value is right-padded with zero bytes or truncated"]
+                    fn string_to_bytes32(value: String) -> [u8; 32] {
+                        let mut output = [0u8; 32];
+                        let length = value.len().min(32);
+                        output[..length].copy_from_slice(&value.as_bytes()[..length]);
+                        output
+                    }
+                }
+            }
+            Helper::ShlBytes => {
+                quote! {
+                    #[doc = "Shifts the bits of `bytesN` to the left, as the `<<` operator of Solidity"]
+                    fn shl_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+                        let mut output = [0u8; N];
+                        let shift = shift.min(8 * N as u128) as usize;
+                        let (bytes, bits) = (shift / 8, shift % 8);
+                        for i in 0..N - bytes {
+                            output[i] = value[i + bytes] << bits;
+                            if bits > 0 && i + bytes + 1 < N {
+                                output[i] |= value[i + bytes + 1] >> (8 - bits);
+                            }
+                        }
+                        output
+                    }
+                }
+            }
+            Helper::ShrBytes => {
+                quote! {
+                    #[doc = "Shifts the bits of `bytesN` to the right, as the `>>` operator of Solidity"]
+                    fn shr_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {
+                        let mut output = [0u8; N];
+                        let shift = shift.min(8 * N as u128) as usize;
+                        let (bytes, bits) = (shift / 8, shift % 8);
+                        for i in bytes..N {
+                            output[i] = value[i - bytes] >> bits;
+                            if bits > 0 && i > bytes {
+                                output[i] |= value[i - bytes - 1] << (8 - bits);
+                            }
+                        }
+                        output
+                    }
+                }
+            }
+            Helper::AndBytes => {
+                quote! {
+                    #[doc = "Applies `&` to each byte of two `bytesN` values"]
+                    fn and_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+                        let mut output = [0u8; N];
+                        for i in 0..N {
+                            output[i] = left[i] & right[i];
+                        }
+                        output
+                    }
+                }
+            }
+            Helper::OrBytes => {
+                quote! {
+                    #[doc = "Applies `|` to each byte of two `bytesN` values"]
+                    fn or_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+                        let mut output = [0u8; N];
+                        for i in 0..N {
+                            output[i] = left[i] | right[i];
+                        }
+                        output
+                    }
+                }
+            }
+            Helper::XorBytes => {
+                quote! {
+                    #[doc = "Applies `^` to each byte of two `bytesN` values"]
+                    fn xor_bytes<const N: usize>(left: [u8; N], right: [u8; N]) -> [u8; N] {
+                        let mut output = [0u8; N];
+                        for i in 0..N {
+                            output[i] = left[i] ^ right[i];
+                        }
+                        output
+                    }
+                }
+            }
+        });
+        output.extend(quote! {
+            _blank_!();
+        });
+    }
+
+    output
+}
+
 /// Assembles ink! trait function headers from the vec of parsed FunctionHeader structs and return them as a vec of Strings
 fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStream {
     let mut output = TokenStream::new();
@@ -653,7 +1016,7 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
 
         // assemble function name
         function_name
-            .extend(TokenStream::from_str(&format!("fn {}", header.name.to_case(Snake))).unwrap());
+            .extend(TokenStream::from_str(&format!("fn {}", snake_case(&header.name))).unwrap());
 
         // assemble view
         view.extend(
@@ -666,7 +1029,7 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
 
         // assemble params
         for param in header.params.iter() {
-            let param_name = format_ident!("{}", param.name.to_case(Snake));
+            let param_name = format_ident!("{}", snake_case(&param.name));
             let param_type = TokenStream::from_str(&param.param_type).unwrap();
 
             params.extend(quote! {
@@ -718,6 +1081,16 @@ fn assemble_function_headers(function_headers: Vec<FunctionHeader>) -> TokenStre
     output
 }
 
+/// Assembles the key of a mapping, multiple indices are joined into a tuple
+fn assemble_mapping_key(indices: &[Expression]) -> TokenStream {
+    if indices.len() > 1 {
+        quote!((#(#indices),*))
+    } else {
+        let expression = indices.first().unwrap();
+        quote!(#expression)
+    }
+}
+
 /// Adds a signature to the beginning of the file :)
 fn signature() -> TokenStream {
     const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -788,13 +1161,16 @@ impl ToTokens for Statement {
                     _comment_!(#content);
                 })
             }
-            Statement::Declaration(var_name_raw, var_type_raw, initial_value_maybe) => {
-                let var_name = format_ident!("{}", var_name_raw.to_case(Snake));
+            Statement::Declaration(var_name_raw, var_type_raw, initial_value_maybe, mutable) => {
+                let var_name = format_ident!("{}", snake_case(var_name_raw));
                 let var_type = TokenStream::from_str(var_type_raw).unwrap();
-                if let Some(initial_value) = &initial_value_maybe {
-                    stream.extend(quote!(let #var_name : #var_type = #initial_value;));
+                let mutability = if *mutable { quote!(mut) } else { quote!() };
+                if var_type_raw.is_empty() {
+                    stream.extend(quote!(let #mutability #var_name = #initial_value_maybe;));
+                } else if let Some(initial_value) = &initial_value_maybe {
+                    stream.extend(quote!(let #mutability #var_name : #var_type = #initial_value;));
                 } else {
-                    stream.extend(quote!(let #var_name : #var_type;));
+                    stream.extend(quote!(let #mutability #var_name : #var_type;));
                 }
             }
             Statement::Loop(assign, condition, modification, statements) => {
@@ -803,12 +1179,30 @@ impl ToTokens for Statement {
                     loop {
                         #(#statements)*
                         #modification
-                        if #condition {
+                        if !(#condition) {
                             break;
                         }
                     }
                 })
             }
+            Statement::For(variable_raw, range, statements) => {
+                let variable = format_ident!("{}", snake_case(variable_raw));
+                stream.extend(quote! {
+                    for #variable in #range {
+                        #(#statements)*
+                    }
+                })
+            }
+            Statement::Break => {
+                stream.extend(quote! {
+                    break;
+                })
+            }
+            Statement::Continue => {
+                stream.extend(quote! {
+                    continue;
+                })
+            }
             Statement::Group(statements) => {
                 stream.extend(quote! {
                         #(#statements)*
@@ -865,10 +1259,10 @@ impl ToTokens for Statement {
             Statement::IfEnd => {}
             Statement::ModifierBody => {
                 stream.extend(quote! {
-                    body(instance);
+                    let result = body(instance)?;
                 })
             }
-            Statement::Raw(_) => {}
+            Statement::Raw(..) => {}
             Statement::Require(condition_raw, error_raw) => {
                 let left = &condition_raw.left;
                 let operation = condition_raw.operation;
@@ -889,6 +1283,21 @@ impl ToTokens for Statement {
                     return Ok(#output)
                 })
             }
+            Statement::Revert(error_raw, args, constructor) => {
+                let error_name = format_ident!("{}", error_raw);
+                let error = if args.is_empty() {
+                    quote!(Error::#error_name)
+                } else {
+                    quote!(Error::#error_name(#(#args),*))
+                };
+                stream.extend(
+                    if *constructor {
+                        quote!(panic!("{:?}", #error);)
+                    } else {
+                        quote!(return Err(#error);)
+                    },
+                )
+            }
             Statement::Ternary(condition_raw, if_true, if_false) => {
                 let left = &condition_raw.left;
                 let operation = condition_raw.operation;
@@ -945,14 +1354,46 @@ impl ToTokens for Expression {
                     quote!(#left #operation #right)
                 }
             }
+            Expression::ArrayIndex(array, index) => {
+                quote!(#array[#index as usize])
+            }
+            Expression::BaseCall(function_name_raw, args, selector_raw, fallible, panics) => {
+                let function_name = format_ident!("{}", function_name_raw);
+                let selector = TokenStream::from_str(selector_raw).unwrap();
+                // the constructor does not return `Result`, so the error can not be propagated
+                if *fallible && *panics {
+                    let message = format!("{function_name_raw} failed");
+                    quote!(#selector.#function_name(#(#args),*).expect(#message))
+                } else if *fallible {
+                    quote!(#selector.#function_name(#(#args),*)?)
+                } else {
+                    quote!(#selector.#function_name(#(#args),*))
+                }
+            }
             Expression::Cast(unique_cast, cast_type_raw, expression) => {
                 let cast_type = TokenStream::from_str(cast_type_raw).unwrap();
-                if *unique_cast {
+                if let (true, Expression::Length(array)) = (*unique_cast, expression.as_ref()) {
+                    // the length is converted from `usize` directly
+                    quote!(#cast_type(#array.len()))
+                } else if *unique_cast {
                     quote!(#cast_type(#expression))
                 } else {
                     quote!((#expression as #cast_type))
                 }
             }
+            Expression::CheckedArithmetic(left, right, operation, panics) => {
+                let (method, message) = match operation {
+                    Operation::Add => (quote!(checked_add), "Overflow"),
+                    Operation::Subtract => (quote!(checked_sub), "Underflow"),
+                    Operation::Mul => (quote!(checked_mul), "Overflow"),
+                    _ => (quote!(checked_div), "Division by zero"),
+                };
+                if *panics {
+                    quote!(#left.#method(#right).expect(#message))
+                } else {
+                    quote!(#left.#method(#right).ok_or(Error::Custom(String::from(#message)))?)
+                }
+            }
             Expression::Condition(condition_raw) => {
                 let left = &condition_raw.left;
                 let operation = condition_raw.operation;
@@ -971,11 +1412,40 @@ impl ToTokens for Expression {
             Expression::Enclosed(expression) => {
                 quote!((#expression))
             }
+            Expression::BlockNumber(selector_raw) => {
+                let selector =
+                    TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
+                quote!(#selector.env().block_number())
+            }
+            Expression::BlockTimestamp(selector_raw) => {
+                let selector =
+                    TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
+                quote!(#selector.env().block_timestamp())
+            }
+            Expression::EnvAccountId(selector_raw) => {
+                let selector =
+                    TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
+                quote!(#selector.env().account_id())
+            }
+            Expression::EnvBalance(selector_raw) => {
+                let selector =
+                    TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
+                quote!(#selector.env().balance())
+            }
             Expression::EnvCaller(selector_raw) => {
                 let selector =
                     TokenStream::from_str(&selector_raw.clone().unwrap_or_default()).unwrap();
                 quote!(#selector.env().caller())
             }
+            Expression::ExternalCall(interface_raw, function_name_raw, address, args, panics) => {
+                let interface_ref = format_ident!("{}Ref", interface_raw);
+                let function_name = format_ident!("{}", snake_case(function_name_raw));
+                if *panics {
+                    quote!(#interface_ref::#function_name(&#address #(,#args)*).unwrap())
+                } else {
+                    quote!(#interface_ref::#function_name(&#address #(,#args)*)?)
+                }
+            }
             Expression::FunctionCall(function_name_raw, args_raw, selector_maybe, external) => {
                 let mut function_call = TokenStream::new();
                 if let Some(selector_raw) = selector_maybe {
@@ -985,7 +1455,7 @@ impl ToTokens for Expression {
                 let function_name = format_ident!(
                     "{}{}",
                     if *external { "" } else { "_" },
-                    function_name_raw.to_case(Snake)
+                    snake_case(function_name_raw)
                 );
                 let mut args = TokenStream::new();
                 for (i, arg) in args_raw.iter().enumerate() {
@@ -999,6 +1469,10 @@ impl ToTokens for Expression {
                     #function_call #function_name(#args)?
                 }
             }
+            Expression::HelperCall(helper, args) => {
+                let helper_name = format_ident!("{}", helper.name());
+                quote!(#helper_name(#(#args),*))
+            }
             Expression::IsZero(expression) => {
                 quote!(#expression.is_zero())
             }
@@ -1011,7 +1485,7 @@ impl ToTokens for Expression {
                 quote!(#left #operation #right)
             }
             Expression::Member(expression_raw, selector_raw) => {
-                let expression_maybe = TokenStream::from_str(&expression_raw.to_case(Snake));
+                let expression_maybe = TokenStream::from_str(&snake_case(expression_raw));
                 if let Ok(expression) = expression_maybe {
                     if let Some(selector_raw) = selector_raw {
                         let selector = format_ident!("{}", selector_raw);
@@ -1024,36 +1498,33 @@ impl ToTokens for Expression {
                 }
             }
             Expression::Mapping(expression, indices_raw, insert_maybe) => {
-                let indices = if indices_raw.len() > 1 {
-                    let mut inner = TokenStream::new();
-                    for i in 0..indices_raw.len() {
-                        let expression = indices_raw.get(i).unwrap();
-                        if i > 0 {
-                            inner.extend(quote!(,));
-                        }
-                        inner.extend(quote!(#expression));
-                    }
-                    quote!((#inner))
-                } else {
-                    let expression = indices_raw.get(0).unwrap();
-                    quote!(#expression)
-                };
+                let indices = assemble_mapping_key(indices_raw);
                 if let Some(insert) = insert_maybe {
                     quote!(#expression.insert(&#indices, &(#insert)))
                 } else {
                     quote!(#expression.get(&#indices).unwrap_or_default())
                 }
             }
+            Expression::MappingRemove(expression, indices_raw) => {
+                let indices = assemble_mapping_key(indices_raw);
+                quote!(#expression.remove(&#indices))
+            }
+            Expression::Length(array) => {
+                quote!((#array.len() as u128))
+            }
             Expression::Modifier(modifier_raw) => {
                 let modifier = TokenStream::from_str(modifier_raw).unwrap();
                 quote!(#modifier)
             }
             Expression::NewArray(array_type_raw, array_size) => {
                 let array_type = TokenStream::from_str(array_type_raw).unwrap();
-                quote!(vec![#array_type::default(); #array_size])
+                quote!(vec![#array_type::default(); #array_size as usize])
+            }
+            Expression::NotImplemented(content) => {
+                quote!(todo!(#content))
             }
             Expression::StructArg(field_name_raw, value) => {
-                let field_name = TokenStream::from_str(&field_name_raw.to_case(Snake)).unwrap();
+                let field_name = TokenStream::from_str(&snake_case(field_name_raw)).unwrap();
                 quote!(#field_name : #value)
             }
             Expression::StructInit(struct_name_raw, struct_args_raw) => {
@@ -0,0 +1,94 @@
+// MIT License
+
+// Copyright (c) 2022 Supercolony
+
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+/// Options of the transpilation passed to Sol2Ink as command line flags
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// `require` guards repeated in at least three functions are extracted into a helper function
+    pub extract_requires: bool,
+    /// compound assignments of mapping values and returned computations on them return an error
+    /// on overflow and underflow
+    pub checked_arithmetic: bool,
+    /// the output is a standalone crate with `lib.rs` as its root, the default,
+    /// otherwise a module to embed into an existing crate with `--emit-module`
+    pub emit_lib: bool,
+    /// variants of enums are converted to PascalCase, otherwise they keep their solidity names
+    pub normalize_enum_variants: bool,
+    /// unsigned integers wider than 128 bits are `U256` of `primitive_types`, otherwise they are `u128`
+    pub u256: bool,
+    /// shifts of integers are `wrapping_shl` and `wrapping_shr`, which do not panic when
+    /// the shift amount is not less than the width of the integer
+    pub wrapping_shifts: bool,
+    /// the crate of the OpenBrush macros and imports, `brush` for its older versions
+    pub macro_namespace: String,
+    /// comments of constructs which could not be translated end with their location
+    /// in the Solidity source code, like `// L12:C9`
+    pub inline_locations: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            extract_requires: false,
+            checked_arithmetic: false,
+            emit_lib: true,
+            normalize_enum_variants: false,
+            u256: false,
+            wrapping_shifts: false,
+            macro_namespace: String::from("openbrush"),
+            inline_locations: false,
+        }
+    }
+}
+
+impl Config {
+    /// Creates the config from the command line flags
+    ///
+    /// `flags` the command line arguments following the name of the file
+    ///
+    /// returns the config or the unknown flag as an error
+    pub fn from_args(flags: &[String]) -> Result<Config, String> {
+        let mut config = Config::default();
+
+        for flag in flags.iter() {
+            match flag.as_str() {
+                "--extract-requires" => config.extract_requires = true,
+                "--checked-arithmetic" => config.checked_arithmetic = true,
+                "--emit-module" => config.emit_lib = false,
+                "--normalize-enum-variants" => config.normalize_enum_variants = true,
+                "--u256" => config.u256 = true,
+                "--wrapping-shifts" => config.wrapping_shifts = true,
+                "--inline-locations" => config.inline_locations = true,
+                _ => {
+                    match flag.strip_prefix("--macro-namespace=") {
+                        Some(namespace) if !namespace.is_empty() => {
+                            config.macro_namespace = namespace.to_owned()
+                        }
+                        _ => return Err(format!("Unknown flag {flag}")),
+                    }
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
@@ -39,7 +39,10 @@ use rust_format::{
     RustFmt,
 };
 
-use crate::toml_builder;
+use crate::{
+    config,
+    toml_builder,
+};
 
 /// Reads the file to be transpiled and returns it as string
 ///
@@ -56,24 +59,40 @@ pub fn read_file(path: &String) -> std::io::Result<String> {
 ///
 /// `lines` the transpiled file in the form of vec of strings
 /// each item in the vec represents a separate line in the output file
+/// `config` the options of the transpilation, with `emit_lib` the output is written
+/// as `lib.rs` of a crate with its Cargo.toml, otherwise to a single file as a module
 pub fn write_file(
     lines: TokenStream,
     file_name: Option<String>,
+    config: &config::Config,
 ) -> std::io::Result<()> {
     let path = file_name.unwrap_or_else(|| String::from("output"));
-    create_dir_all(&path)?;
+    let code = format_tokens(lines);
 
+    if !config.emit_lib {
+        let mut file = File::create(format!("{path}.rs"))?;
+        return file.write_all(code.as_bytes())
+    }
+
+    create_dir_all(&path)?;
     let mut file = File::create(format!("{path}/lib.rs"))?;
-    let config = Config::new_str().post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
-    file.write_all(
-        RustFmt::from_config(config)
-            .format_tokens(lines)
-            .unwrap()
-            .as_bytes(),
-    )?;
+    file.write_all(code.as_bytes())?;
 
+    let features = toml_builder::openbrush_features(&code);
     let mut cargo_toml = File::create(format!("{path}/Cargo.toml"))?;
-    cargo_toml.write_all(toml_builder::generate_cargo_toml().as_bytes())?;
+    let primitive_types = code.contains("primitive_types::");
+    cargo_toml.write_all(
+        toml_builder::generate_cargo_toml(&features, primitive_types, &config.macro_namespace)
+            .as_bytes(),
+    )?;
 
     Ok(())
 }
+
+/// Formats the transpiled code with rustfmt and returns it as string
+///
+/// `lines` the transpiled file in the form of token stream
+pub fn format_tokens(lines: TokenStream) -> String {
+    let config = Config::new_str().post_proc(PostProcess::ReplaceMarkersAndDocBlocks);
+    RustFmt::from_config(config).format_tokens(lines).unwrap()
+}
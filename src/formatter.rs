@@ -20,6 +20,11 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use convert_case::{
+    Boundary,
+    Case::Snake,
+    Casing,
+};
 use regex::Regex;
 
 /// Splits a string by a string and returns the output
@@ -43,3 +48,15 @@ pub fn trim(line: &str) -> String {
     let regex = Regex::new(r"\s+").unwrap();
     regex.replace_all(line, " ").trim().to_string()
 }
+
+/// Converts the name to snake case, keeping the digits attached to the word before them,
+/// so `key0` stays `key0` rather than becoming `key_0`
+///
+/// `name` the name to be converted
+pub fn snake_case(name: &str) -> String {
+    let boundaries = Boundary::defaults()
+        .into_iter()
+        .filter(|boundary| *boundary != Boundary::LowerDigit)
+        .collect::<Vec<_>>();
+    name.with_boundaries(&boundaries).to_case(Snake)
+}
@@ -20,17 +20,17 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-#![feature(once_cell)]
 #![feature(string_remove_matches)]
-#![feature(exclusive_range_pattern)]
 
 pub mod assembler;
+pub mod config;
 pub mod file_utils;
 pub mod formatter;
 pub mod parser;
 pub mod structures;
 pub mod toml_builder;
 
+use proc_macro2::TokenStream;
 use std::{
     collections::{
         HashMap,
@@ -39,7 +39,11 @@ use std::{
     env,
 };
 
-use crate::parser::ParserError;
+use crate::{
+    config::Config,
+    parser::ParserError,
+    structures::Diagnostic,
+};
 
 fn main() {
     let args: Vec<String> = env::args().collect();
@@ -49,7 +53,15 @@ fn main() {
         return
     }
 
-    std::process::exit(match run(&args[1]) {
+    let config = match Config::from_args(&args[2..]) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1)
+        }
+    };
+
+    std::process::exit(match run(&args[1], &config) {
         Ok(_) => 0,
         Err(err) => {
             eprintln!("error: {:?}", err);
@@ -58,9 +70,32 @@ fn main() {
     });
 }
 
-fn run(path: &String) -> Result<(), parser::ParserError> {
+fn run(path: &String, config: &Config) -> Result<(), parser::ParserError> {
     // read the file
     let content = file_utils::read_file(path)?;
+    let (output, diagnostics) = transpile(&content, config)?;
+    for diagnostic in diagnostics.iter() {
+        eprintln!(
+            "warning: {path}:{}:{}: {}",
+            diagnostic.location.line, diagnostic.location.column, diagnostic.message
+        );
+    }
+    let file_name = path.replace(".sol", "");
+    file_utils::write_file(output, Some(file_name), config)?;
+    println!("File saved!");
+    Ok(())
+}
+
+/// Transpiles the Solidity source code of a contract or an interface
+///
+/// `content` the Solidity source code
+/// `config` the options of the transpilation
+///
+/// returns the ink! code as a `TokenStream` and the constructs which could not be translated
+fn transpile(
+    content: &str,
+    config: &Config,
+) -> Result<(TokenStream, Vec<Diagnostic>), ParserError> {
     let mut chars = content.chars();
     let mut imports = HashSet::new();
     let mut storage = HashMap::new();
@@ -77,35 +112,60 @@ fn run(path: &String) -> Result<(), parser::ParserError> {
         &mut events,
         &mut modifiers,
         &mut structs,
+        config,
     );
-    let output = parser.parse_file()?;
-    match output {
-        (None, None) | (Some(_), Some(_)) => Err(ParserError::FileCorrupted),
+    let output = match parser.parse_file()? {
+        (None, None) | (Some(_), Some(_)) => return Err(ParserError::FileCorrupted),
         (Some(contract), None) => {
-            let ink_contract = assembler::assemble_contract(contract);
-            let file_name = path.replace(".sol", "");
-            file_utils::write_file(ink_contract, Some(file_name))?;
-            println!("File saved!");
-            Ok(())
+            let output = assembler::assemble_contract(contract, &config.macro_namespace);
+            if config.emit_lib {
+                assembler::assemble_lib(output)
+            } else {
+                output
+            }
         }
         (None, Some(interface)) => {
-            let ink_trait = assembler::assemble_interface(interface);
-            let file_name = path.replace(".sol", "");
-            file_utils::write_file(ink_trait, Some(file_name))?;
-            println!("File saved!");
-            Ok(())
+            assembler::assemble_interface(interface, &config.macro_namespace)
         }
-    }
+    };
+
+    Ok((output, parser.diagnostics().to_vec()))
 }
 
 #[cfg(test)]
 mod test {
-    use crate::run;
+    use crate::{
+        config::Config,
+        file_utils,
+        parser::ParserError,
+        run,
+        toml_builder,
+        transpile,
+    };
+
+    /// Transpiles the Solidity source code and returns the formatted ink! code
+    fn transpile_source(content: &str) -> String {
+        transpile_source_with_config(content, &Config::default())
+    }
+
+    /// Transpiles the Solidity source code with the given config and returns the formatted ink! code
+    fn transpile_source_with_config(content: &str, config: &Config) -> String {
+        file_utils::format_tokens(transpile(content, config).unwrap().0)
+    }
+
+    /// Returns true if the output contains the code, ignoring whitespace
+    fn contains_code(output: &str, code: &str) -> bool {
+        let strip = |text: &str| text.split_whitespace().collect::<String>();
+        strip(output).contains(&strip(code))
+    }
 
     #[test]
     fn erc20() {
         assert_eq!(
-            run(&"examples/contracts/ERC20/ERC20.sol".to_string()),
+            run(
+                &"examples/contracts/ERC20/ERC20.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -113,7 +173,10 @@ mod test {
     #[test]
     fn erc721() {
         assert_eq!(
-            run(&"examples/contracts/ERC721/ERC721.sol".to_string()),
+            run(
+                &"examples/contracts/ERC721/ERC721.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -121,7 +184,10 @@ mod test {
     #[test]
     fn erc1155() {
         assert_eq!(
-            run(&"examples/contracts/ERC1155/ERC1155.sol".to_string()),
+            run(
+                &"examples/contracts/ERC1155/ERC1155.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -129,7 +195,10 @@ mod test {
     #[test]
     fn access_control() {
         assert_eq!(
-            run(&"examples/contracts/AccessControl/AccessControl.sol".to_string()),
+            run(
+                &"examples/contracts/AccessControl/AccessControl.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -137,7 +206,10 @@ mod test {
     #[test]
     fn solang_example() {
         assert_eq!(
-            run(&"examples/contracts/SolangExample/example.sol".to_string()),
+            run(
+                &"examples/contracts/SolangExample/example.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -145,7 +217,10 @@ mod test {
     #[test]
     fn flipper() {
         assert_eq!(
-            run(&"examples/contracts/Flipper/flipper.sol".to_string()),
+            run(
+                &"examples/contracts/Flipper/flipper.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -153,7 +228,10 @@ mod test {
     #[test]
     fn primitives() {
         assert_eq!(
-            run(&"examples/contracts/Primitives/Primitives.sol".to_string()),
+            run(
+                &"examples/contracts/Primitives/Primitives.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -161,7 +239,10 @@ mod test {
     #[test]
     fn ierc20() {
         assert_eq!(
-            run(&"examples/interfaces/IERC20/IERC20.sol".to_string()),
+            run(
+                &"examples/interfaces/IERC20/IERC20.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -169,7 +250,10 @@ mod test {
     #[test]
     fn ierc721() {
         assert_eq!(
-            run(&"examples/interfaces/IERC721/IERC721.sol".to_string()),
+            run(
+                &"examples/interfaces/IERC721/IERC721.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -177,7 +261,10 @@ mod test {
     #[test]
     fn ierc1155() {
         assert_eq!(
-            run(&"examples/interfaces/IERC1155/IERC1155.sol".to_string()),
+            run(
+                &"examples/interfaces/IERC1155/IERC1155.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
@@ -185,8 +272,3004 @@ mod test {
     #[test]
     fn iaccess_control() {
         assert_eq!(
-            run(&"examples/interfaces/IAccessControl/IAccessControl.sol".to_string()),
+            run(
+                &"examples/interfaces/IAccessControl/IAccessControl.sol".to_string(),
+                &Config::default()
+            ),
             Ok(())
         );
     }
+
+    #[test]
+    fn post_increment_in_return() {
+        let output = transpile_source(
+            "contract counter {
+                uint256[] values;
+                uint256 index;
+
+                function next() public returns (uint256) {
+                    return values[index++];
+                }
+            }",
+        );
+        assert!(output.contains("let index_tmp = self.data.index;"));
+        assert!(output.contains("self.data.index += 1;"));
+        assert!(output.contains("return Ok(self.data.values[index_tmp as usize])"));
+    }
+
+    #[test]
+    fn increments_in_else_if_and_repeated() {
+        let output = transpile_source(
+            "contract counter {
+                uint256[] values;
+                uint256 index;
+
+                function pair() public returns (uint256) {
+                    return values[index++] + values[index++];
+                }
+
+                function check(uint256 a) public returns (uint256) {
+                    if (a == 0) {
+                        return 0;
+                    } else if (values[index++] > a) {
+                        return 1;
+                    }
+                    require(a > 1, \"a++ is not allowed\");
+                    return 2;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "let index_tmp = self.data.index;
+            self.data.index += 1;
+            let index_tmp_1 = self.data.index;
+            self.data.index += 1;
+            return Ok(self.data.values[index_tmp as usize] + self.data.values[index_tmp_1 as usize])"
+        ));
+        assert!(contains_code(
+            &output,
+            "} else if {
+                let index_tmp = self.data.index;
+                self.data.index += 1;
+                self.data.values[index_tmp as usize] > a
+            } {
+                return Ok(1)
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "if a <= 1 {
+                return Err(Error::Custom(String::from(\"a++ is not allowed\")))
+            }"
+        ));
+    }
+
+    #[test]
+    fn repeated_requires_extracted() {
+        let source = "contract vault {
+            bool paused;
+            uint256 total;
+
+            function deposit(uint256 amount) public {
+                require(!paused, \"Vault: paused\");
+                total += amount;
+            }
+
+            function withdraw(uint256 amount) public {
+                require(!paused, \"Vault: paused\");
+                total -= amount;
+            }
+
+            function reset() public {
+                require(!paused, \"Vault: paused\");
+                total = 0;
+            }
+        }";
+        let config = Config {
+            extract_requires: true,
+            ..Default::default()
+        };
+
+        let output = transpile_source_with_config(source, &config);
+        assert!(output.contains("fn _validate_vault_paused(&self) -> Result<(), Error> {"));
+        assert_eq!(output.matches("self._validate_vault_paused()?;").count(), 3);
+        assert_eq!(output.matches("if self.data.paused {").count(), 1);
+
+        let output = transpile_source(source);
+        assert!(!output.contains("_validate_vault_paused"));
+        assert_eq!(output.matches("if self.data.paused {").count(), 3);
+    }
+
+    #[test]
+    fn repeated_requires_with_same_message() {
+        let function = |name: &str| {
+            format!(
+                "function {name}(bytes32 mask) public {{
+                    require(msg.sender == owner, \"denied\");
+                    require(total < 10, \"denied\");
+                    require(flags & mask == flags, \"bad mask\");
+                    total += 1;
+                }}"
+            )
+        };
+        let source = format!(
+            "contract vault {{
+                address owner;
+                bytes32 flags;
+                uint256 total;
+                {} {} {}
+            }}",
+            function("a"),
+            function("b"),
+            function("c")
+        );
+        let config = Config {
+            extract_requires: true,
+            ..Default::default()
+        };
+
+        let output = transpile_source_with_config(&source, &config);
+        assert!(contains_code(
+            &output,
+            "fn _validate_denied(&self) -> Result<(), Error> {
+                if self.env().caller() != self.data.owner {"
+        ));
+        assert!(contains_code(
+            &output,
+            "fn _validate_denied_1(&self) -> Result<(), Error> {
+                if self.data.total >= 10 {"
+        ));
+        // the guard using the parameter stays in the functions
+        assert!(!output.contains("_validate_bad_mask"));
+        assert_eq!(
+            output
+                .matches("if and_bytes(self.data.flags, mask) != self.data.flags {")
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn bytes32_string_conversion() {
+        let output = transpile_source(
+            "contract token {
+                bytes32 symbolBytes;
+
+                function symbol() public view returns (string memory) {
+                    return string(abi.encodePacked(symbolBytes));
+                }
+
+                function setSymbol(string memory value) public {
+                    symbolBytes = bytes32(bytes(value));
+                }
+            }",
+        );
+        assert!(output.contains("return Ok(bytes32_to_string(self.data.symbol_bytes))"));
+        // only the trailing zero bytes are trimmed, so `a\0b\0...` converts to `a\0b`
+        assert!(contains_code(
+            &output,
+            "fn bytes32_to_string(value: [u8; 32]) -> String {
+                let length = value
+                    .iter()
+                    .rposition(|byte| *byte != 0)
+                    .map_or(0, |index| index + 1);
+                String::from_utf8_lossy(&value[..length]).into_owned()
+            }"
+        ));
+        assert!(output.contains("self.data.symbol_bytes = string_to_bytes32(value);"));
+        assert!(output.contains("fn string_to_bytes32(value: String) -> [u8; 32] {"));
+    }
+
+    #[test]
+    fn view_mapping_read() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) private _balances;
+
+                function balanceOf(address account) public view returns (uint256) {
+                    return _balances[account];
+                }
+            }",
+        );
+        assert!(output
+            .contains("pub fn balance_of(&self, account: AccountId) -> Result<u128, Error> {"));
+        assert!(output.contains("return Ok(self.data.balances.get(&account).unwrap_or_default())"));
+    }
+
+    #[test]
+    fn address_uint160_cast() {
+        let output = transpile_source(
+            "contract caster {
+                function toAddress(uint256 value) public pure returns (address) {
+                    return address(uint160(value));
+                }
+
+                function toInteger(address account) public pure returns (uint256) {
+                    uint256 result = uint160(account);
+                    return result;
+                }
+
+                function narrow(uint256 value) public pure returns (uint160) {
+                    return uint160(value);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "return Ok(todo!(\"Sol2Ink Not Implemented yet: address(uint160(value))\"))"
+        ));
+        assert!(output.contains(
+            "let result: u128 = todo!(\"Sol2Ink Not Implemented yet: uint160(account)\");"
+        ));
+        assert!(output
+            .contains("pub fn to_integer(&self, account: AccountId) -> Result<u128, Error> {"));
+        assert!(output.contains("return Ok((value as u128))"));
+    }
+
+    #[test]
+    fn constructor_seeds_mapping_in_loop() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                constructor(address[] memory accounts, uint256[] memory amounts) {
+                    for (uint256 i = 0; i < accounts.length; i++) {
+                        balances[accounts[i]] = amounts[i];
+                    }
+                }
+            }",
+        );
+        assert!(output.contains("let mut i: u128 = 0;"));
+        assert!(output.contains("while i < (accounts.len() as u128) {"));
+        assert!(contains_code(
+            &output,
+            "instance.data.balances.insert(&accounts[i as usize], &(amounts[i as usize]));"
+        ));
+        assert!(output.contains("i += 1;"));
+    }
+
+    #[test]
+    fn delete_mapping_struct_field() {
+        let output = transpile_source(
+            "contract market {
+                struct Order {
+                    address owner;
+                    uint256 amount;
+                }
+
+                mapping(uint256 => Order) orders;
+
+                function clear(uint256 id) public {
+                    delete orders[id].amount;
+                }
+            }",
+        );
+        assert!(output
+            .contains("let mut orders_entry = self.data.orders.get(&id).unwrap_or_default();"));
+        assert!(output.contains("orders_entry.amount = Default::default();"));
+        assert!(output.contains("self.data.orders.insert(&id, &(orders_entry));"));
+    }
+
+    #[test]
+    fn interface_mapping_getter() {
+        let output = transpile_source(
+            "interface IToken {
+                function allowance(address owner, address spender) external view returns (uint256);
+
+                function balanceOf(address) external view returns (uint256);
+            }",
+        );
+        assert!(output.contains(
+            "fn allowance(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error>;"
+        ));
+        assert!(output.contains("fn balance_of(&self, arg_0: AccountId) -> Result<u128, Error>;"));
+    }
+
+    #[test]
+    fn large_mapping_value_advice() {
+        let output = transpile_source(
+            "contract registry {
+                struct Record {
+                    address owner;
+                    uint256 created;
+                    uint256 updated;
+                    uint256 expires;
+                    uint256 price;
+                    uint256 fee;
+                    uint256 deposit;
+                    uint256 version;
+                    bool active;
+                }
+
+                struct Counter {
+                    uint256 value;
+                }
+
+                mapping(uint256 => Record) records;
+                mapping(uint256 => Counter) counters;
+            }",
+        );
+        assert!(output.contains(
+            "///Sol2Ink: values of this mapping are structs with 9 fields which are loaded and stored as a whole on every access, consider splitting them into several mappings\n        pub records: Mapping<u128, Record>,"
+        ));
+        assert_eq!(output.matches("Sol2Ink: values of this mapping").count(), 1);
+    }
+
+    #[test]
+    fn abstract_constructor_init() {
+        let output = transpile_source(
+            "abstract contract Ownable {
+                address owner;
+
+                constructor(address initialOwner) {
+                    owner = initialOwner;
+                }
+            }",
+        );
+        assert!(
+            output.contains("fn _init(&mut self, initial_owner: AccountId) -> Result<(), Error> {")
+        );
+        assert!(output.contains("self.data.owner = initial_owner;"));
+        assert!(output.contains("pub fn new() -> Self {"));
+        assert!(!output.contains("instance.data.owner"));
+    }
+
+    #[test]
+    fn mapping_read_as_argument() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function _check(uint256 amount) internal {
+                }
+
+                function check(address to) public {
+                    _check(balances[to]);
+                }
+            }",
+        );
+        assert!(output
+            .contains("let balances_value = self.data.balances.get(&to).unwrap_or_default();"));
+        assert!(output.contains("self._check(balances_value)?;"));
+    }
+
+    #[test]
+    fn event_field_types() {
+        let output = transpile_source(
+            "contract shop {
+                enum Status { Open, Closed }
+
+                event StatusChanged(address payable indexed owner, Status status);
+                event Paid(address, uint256);
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub struct StatusChanged { #[ink(topic)] owner: AccountId, status: Status, }"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub struct Paid { arg_0: AccountId, arg_1: u128, }"
+        ));
+    }
+
+    #[test]
+    fn require_dynamic_message() {
+        let output = transpile_source(
+            "contract guard {
+                mapping(address => uint256) balances;
+
+                function take(address account, uint256 amount, string memory note) public {
+                    require(balances[account] >= amount, string(abi.encodePacked(\"Insufficient {balance}, note: \", note)));
+                    require(amount > 0, string.concat(\"Amount \", \"{zero}\"));
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "return Err(Error::Custom(format!(\"Insufficient {{balance}}, note: {}\", note)))"
+        ));
+        assert!(output.contains("return Err(Error::Custom(String::from(\"Amount {zero}\")))"));
+    }
+
+    #[test]
+    fn interface_payable_method() {
+        let output = transpile_source(
+            "interface IVault {
+                function deposit() external payable;
+
+                function total() external view returns (uint256);
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(message, payable)] fn deposit(&mut self) -> Result<(), Error>;"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[ink(message)] fn total(&self) -> Result<u128, Error>;"
+        ));
+    }
+
+    #[test]
+    fn checked_mapping_decrement() {
+        let source = "contract token {
+            mapping(address => uint256) balances;
+
+            function burn(address from, uint256 amount) public {
+                balances[from] -= amount;
+            }
+        }";
+        let config = Config {
+            checked_arithmetic: true,
+            ..Default::default()
+        };
+
+        let output = transpile_source_with_config(source, &config);
+        assert!(contains_code(
+            &output,
+            "self.data.balances.insert(&from, &(self.data.balances.get(&from).unwrap_or_default().checked_sub(amount).ok_or(Error::Custom(String::from(\"Underflow\")))?)"
+        ));
+
+        let output = transpile_source(source);
+        assert!(contains_code(
+            &output,
+            "self.data.balances.insert(&from, &(self.data.balances.get(&from).unwrap_or_default() - amount)"
+        ));
+    }
+
+    #[test]
+    fn type_metadata() {
+        let output = transpile_source(
+            "contract MyContract {
+                function name() public pure returns (string memory) {
+                    return type(MyContract).name;
+                }
+
+                function max() public pure returns (uint256) {
+                    return type(uint256).max;
+                }
+
+                function id() public pure returns (bytes4) {
+                    return type(IERC20).interfaceId;
+                }
+            }",
+        );
+        assert!(output.contains("return Ok(String::from(\"MyContract\"))"));
+        assert!(output.contains("return Ok(u128::MAX)"));
+        assert!(contains_code(
+            &output,
+            "return Ok(todo!(\"Sol2Ink Not Implemented yet: type(IERC20).interfaceId\"))"
+        ));
+    }
+
+    #[test]
+    fn mapping_read_in_condition() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function drain(address user) public returns (uint256) {
+                    if (balances[user] > 0) {
+                        return 1;
+                    }
+                    while (balances[user] > 100) {
+                        balances[user] -= 1;
+                    }
+                    return 0;
+                }
+            }",
+        );
+        assert!(output.contains("if self.data.balances.get(&user).unwrap_or_default() > 0 {"));
+        assert!(output.contains("while self.data.balances.get(&user).unwrap_or_default() > 100 {"));
+    }
+
+    #[test]
+    fn access_control_base() {
+        let output = transpile_source(
+            "contract Minter is AccessControl {
+                bytes32 public constant MINTER_ROLE = keccak256(\"MINTER_ROLE\");
+                uint256 total;
+
+                constructor() {
+                    _setupRole(DEFAULT_ADMIN_ROLE, msg.sender);
+                    grantRole(MINTER_ROLE, msg.sender);
+                }
+
+                function mint(uint256 amount) public onlyRole(MINTER_ROLE) {
+                    total += amount;
+                }
+
+                function isMinter(address account) public view returns (bool) {
+                    return hasRole(MINTER_ROLE, account);
+                }
+
+                function addMinter(address account) public {
+                    grantRole(MINTER_ROLE, account);
+                }
+            }",
+        );
+        assert!(output.contains("contracts::access_control::*"));
+        assert!(output.contains("AccessControlError(AccessControlError),"));
+        assert!(output.contains(
+            "pub const MINTER_ROLE: RoleType = ink_lang::selector_id!(\"MINTER_ROLE\");"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[storage_field] access: access_control::Data,"
+        ));
+        assert!(output.contains("impl AccessControl for Minter {}"));
+        assert!(
+            output.contains("instance._setup_role(DEFAULT_ADMIN_ROLE, instance.env().caller());")
+        );
+        assert!(contains_code(
+            &output,
+            "instance.grant_role(MINTER_ROLE, instance.env().caller()).expect(\"grant_role failed\");"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[modifiers(only_role(MINTER_ROLE))] pub fn mint(&mut self, amount: u128)"
+        ));
+        assert!(output.contains("return Ok(self.has_role(MINTER_ROLE, account))"));
+        assert!(output.contains("self.grant_role(MINTER_ROLE, account)?;"));
+        assert!(toml_builder::generate_cargo_toml(
+            &toml_builder::openbrush_features(&output),
+            false,
+            "openbrush"
+        )
+        .contains("features = [\"access_control\"]"));
+    }
+
+    #[test]
+    fn struct_with_mapping_rejected() {
+        let output = transpile_source(
+            "contract voting {
+                struct Proposal {
+                    uint256 votes;
+                    mapping(address => bool) voted;
+                }
+
+                mapping(uint256 => Proposal) proposals;
+            }",
+        );
+        assert!(output.contains(
+            "///Sol2Ink: field `voted` of type `Mapping<AccountId, bool>` was removed, mappings can not be encoded inside of a struct"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub struct Proposal { votes: u128, }"
+        ));
+    }
+
+    #[test]
+    fn bool_success_return() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function transfer(address to, uint256 amount) public returns (bool) {
+                    require(balances[msg.sender] >= amount, \"Not enough\");
+                    if (amount == 0) return false;
+                    balances[to] += amount;
+                    return true;
+                }
+            }",
+        );
+        assert!(output.contains(
+            "pub fn transfer(&mut self, to: AccountId, amount: u128) -> Result<bool, Error> {"
+        ));
+        assert!(output.contains("return Err(Error::Custom(String::from(\"Not enough\")))"));
+        assert!(output.contains("return Ok(false)"));
+        assert!(output.contains("return Ok(true)"));
+    }
+
+    #[test]
+    fn mapping_array_index_assign() {
+        let output = transpile_source(
+            "contract tokens {
+                mapping(address => uint256[]) userTokens;
+
+                function set(address owner, uint256 i, uint256 tokenId) public {
+                    userTokens[owner][i] = tokenId;
+                }
+
+                function get(address owner, uint256 i) public view returns (uint256) {
+                    return userTokens[owner][i];
+                }
+            }",
+        );
+        assert!(output.contains(
+            "let mut user_tokens_entry = self.data.user_tokens.get(&owner).unwrap_or_default();"
+        ));
+        assert!(output.contains("user_tokens_entry[i as usize] = token_id;"));
+        assert!(output.contains("self.data.user_tokens.insert(&owner, &(user_tokens_entry));"));
+        assert!(output.contains(
+            "return Ok(self.data.user_tokens.get(&owner).unwrap_or_default()[i as usize])"
+        ));
+    }
+
+    #[test]
+    fn pragmas_skipped() {
+        let output = transpile_source(
+            "// SPDX-License-Identifier: MIT
+            pragma solidity ^0.8.0;
+\tpragma experimental ABIEncoderV2;
+            pragma abicoder v2;
+
+            contract pragmas {
+                uint256 value;
+
+                function get() public view returns (uint256) {
+                    return value;
+                }
+            }",
+        );
+        assert!(output.contains("pub mod pragmas {"));
+        assert!(!output.contains("ABIEncoderV2"));
+        assert!(!output.contains("abicoder"));
+        assert!(output.contains("return Ok(self.data.value)"));
+    }
+
+    #[test]
+    fn delete_whole_mapping() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+                uint256 total;
+
+                function reset() public {
+                    delete balances;
+                    delete total;
+                }
+            }",
+        );
+        assert!(output.contains(
+            "// Sol2Ink Not Implemented yet: delete balances; ink! mappings can not be cleared"
+        ));
+        assert!(output.contains("self.data.total = Default::default();"));
+    }
+
+    #[test]
+    fn interface_call_with_struct() {
+        let output = transpile_source(
+            "contract trader {
+                struct Order {
+                    address maker;
+                    uint256 amount;
+                }
+
+                function trade(address market, uint256 amount) public returns (bool) {
+                    return IMarket(market).place(Order(msg.sender, amount));
+                }
+
+                function cancel(address market, uint256 amount) public {
+                    IMarket(market).cancel(Order({maker: msg.sender, amount: amount}), 0);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "return Ok(MarketRef::place(&market, Order { maker: self.env().caller(), amount, },)?)"
+        ));
+        assert!(contains_code(
+            &output,
+            "MarketRef::cancel(&market, Order { maker: self.env().caller(), amount, }, 0,)?;"
+        ));
+    }
+
+    #[test]
+    fn mapping_struct_memory_copy() {
+        let output = transpile_source(
+            "contract book {
+                struct Order {
+                    address maker;
+                    uint256 amount;
+                }
+
+                mapping(uint256 => Order) orders;
+
+                function amountOf(uint256 id) public view returns (uint256) {
+                    Order memory o = orders[id];
+                    require(o.maker != address(0), \"No order\");
+                    return o.amount;
+                }
+            }",
+        );
+        assert!(output.contains("#[derive(Default, Clone, Encode, Decode)]"));
+        assert!(output.contains("let o: Order = self.data.orders.get(&id).unwrap_or_default();"));
+        assert!(output.contains("if o.maker.is_zero() {"));
+        assert!(output.contains("return Ok(o.amount)"));
+    }
+
+    #[test]
+    fn parameterless_error_revert() {
+        let output = transpile_source(
+            "contract guard {
+                error Unauthorized();
+                error TooLow(uint256 value, uint256 min);
+
+                address owner;
+
+                function check(uint256 value) public view {
+                    if (msg.sender != owner) revert Unauthorized();
+                    if (value < 10) {
+                        revert TooLow(value, 10);
+                    }
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub enum Error { Custom(String), Unauthorized, TooLow(u128, u128), }"
+        ));
+        assert!(output.contains("return Err(Error::Unauthorized)"));
+        assert!(output.contains("return Err(Error::TooLow(value, 10))"));
+    }
+
+    #[test]
+    fn nested_public_mapping_getter() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => mapping(address => uint256)) public allowance;
+                mapping(address owner => mapping(address spender => uint256)) public approvals;
+                mapping(address => uint256) private balances;
+            }",
+        );
+        assert!(output.contains(
+            "pub fn allowance(&self, key0: AccountId, key1: AccountId) -> Result<u128, Error> {"
+        ));
+        assert!(output
+            .contains("return Ok(self.data.allowance.get(&(key0, key1)).unwrap_or_default())"));
+        assert!(output.contains(
+            "pub fn approvals(&self, owner: AccountId, spender: AccountId) -> Result<u128, Error> {"
+        ));
+        assert!(output.contains("pub approvals: Mapping<(AccountId, AccountId), u128>,"));
+        assert!(!output.contains("pub fn balances("));
+    }
+
+    #[test]
+    fn receive_override() {
+        let base = transpile_source(
+            "abstract contract Base {
+                uint256 received;
+
+                receive() external payable virtual {
+                    received += msg.value;
+                }
+            }",
+        );
+        assert!(base.contains("self.data.received += self.env().transferred_value();"));
+
+        let output = transpile_source(
+            "contract vault is Base {
+                uint256 received;
+                uint256 calls;
+
+                receive() external payable virtual override {
+                    calls += 1;
+                }
+
+                fallback() external payable override {
+                    calls += 1;
+                }
+            }",
+        );
+        assert_eq!(output.matches("pub fn receive(").count(), 1);
+        assert!(contains_code(
+            &output,
+            "#[ink(message, payable)] pub fn receive(&mut self) -> Result<(), Error> { self.data.calls += 1; Ok(()) }"
+        ));
+        assert!(!output.contains("transferred_value"));
+        assert!(output.contains("pub fn fallback(&mut self) -> Result<(), Error> {"));
+    }
+
+    #[test]
+    fn mapping_assign_from_call() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function computeBalance(address who) internal view returns (uint256) {
+                    return 5;
+                }
+
+                function sync(address to) public {
+                    balances[to] = computeBalance(to);
+                }
+            }",
+        );
+        assert!(output.contains("let balances_value = self._compute_balance(to)?;"));
+        assert!(output.contains("self.data.balances.insert(&to, &(balances_value));"));
+    }
+
+    #[test]
+    fn string_and_bytes_constants() {
+        let output = transpile_source(
+            "contract token {
+                string constant NAME = \"Token\";
+                bytes constant MAGIC = hex\"deadbeef\";
+                bytes constant PREFIX = hex\"abc\";
+
+                function name() public view returns (string memory) {
+                    return NAME;
+                }
+
+                function magic() public view returns (bytes memory) {
+                    return MAGIC;
+                }
+            }",
+        );
+        assert!(output.contains("pub const NAME: &str = \"Token\";"));
+        assert!(output.contains("pub const MAGIC: &[u8] = &[0xde, 0xad, 0xbe, 0xef];"));
+        assert!(output.contains("pub const PREFIX: &[u8] = &[0x0a, 0xbc];"));
+        assert!(output.contains("return Ok(String::from(NAME))"));
+        assert!(output.contains("return Ok(MAGIC.to_vec())"));
+    }
+
+    #[test]
+    fn batch_withdraw_loop() {
+        let source = "contract vault {
+            mapping(address => uint256) balances;
+
+            function batchWithdraw(address[] memory users, uint256[] memory amounts) public {
+                for (uint256 i = 0; i < users.length; i++) {
+                    require(amounts[i] > 0, \"Zero amount\");
+                    require(balances[users[i]] >= amounts[i], \"Insufficient balance\");
+                    balances[users[i]] -= amounts[i];
+                }
+            }
+        }";
+        let config = Config {
+            checked_arithmetic: true,
+            ..Default::default()
+        };
+
+        let output = transpile_source_with_config(source, &config);
+        assert!(contains_code(
+            &output,
+            "while i < (users.len() as u128) {
+                if amounts[i as usize] <= 0 {
+                    return Err(Error::Custom(String::from(\"Zero amount\")))
+                }"
+        ));
+        assert!(
+            output.contains("return Err(Error::Custom(String::from(\"Insufficient balance\")))")
+        );
+        assert!(contains_code(
+            &output,
+            ".checked_sub(amounts[i as usize]).ok_or(Error::Custom(String::from(\"Underflow\")))?),"
+        ));
+        assert!(contains_code(&output, "i += 1; } Ok(())"));
+    }
+
+    #[test]
+    fn emit_lib() {
+        let source = "contract flipper {
+            bool value;
+
+            function flip() public {
+                value = !value;
+            }
+        }";
+
+        let module = transpile_source_with_config(
+            source,
+            &Config {
+                emit_lib: false,
+                ..Default::default()
+            },
+        );
+        assert!(module.starts_with("// Generated with Sol2Ink"));
+        assert!(!module.contains("#![cfg_attr"));
+        assert!(module.contains("#[openbrush::contract]\npub mod flipper {"));
+
+        let lib = transpile_source(source);
+        assert!(lib.starts_with(
+            "#![cfg_attr(not(feature = \"std\"), no_std)]\n#![feature(min_specialization)]\n"
+        ));
+        assert!(lib.contains("#[openbrush::contract]\npub mod flipper {"));
+    }
+
+    #[test]
+    fn bool_mapping_toggle() {
+        let output = transpile_source(
+            "contract registry {
+                mapping(address => bool) approved;
+
+                function toggle(address operator) public {
+                    approved[operator] = !approved[operator];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.data.approved.insert(&operator, &(!self.data.approved.get(&operator).unwrap_or_default()),);"
+        ));
+    }
+
+    #[test]
+    fn enum_variant_normalization() {
+        let source = "contract machine {
+            enum State {
+                PENDING_APPROVAL,
+                ACTIVE,
+                Closed,
+            }
+
+            State state;
+
+            function activate() public {
+                state = State.ACTIVE;
+            }
+        }";
+
+        let output = transpile_source(source);
+        assert!(contains_code(
+            &output,
+            "pub enum State { PENDING_APPROVAL, ACTIVE, Closed, }"
+        ));
+        assert!(output.contains("self.data.state = State::ACTIVE;"));
+
+        let config = Config {
+            normalize_enum_variants: true,
+            ..Default::default()
+        };
+        let output = transpile_source_with_config(source, &config);
+        assert!(contains_code(
+            &output,
+            "pub enum State { PendingApproval, Active, Closed, }"
+        ));
+        assert!(output.contains("self.data.state = State::Active;"));
+    }
+
+    #[test]
+    fn enum_explicit_discriminants() {
+        let output = transpile_source(
+            "contract machine {
+                enum Level {
+                    Low = 1,
+                    Medium = 5,
+                    High = 10
+                }
+
+                enum Fee {
+                    Free,
+                    Capped = 255,
+                    Full
+                }
+
+                Level level;
+                Fee fee;
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[repr(u8)] pub enum Level { Low = 1, Medium = 5, High = 10, }"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[repr(u16)] pub enum Fee { Free, Capped = 255, Full, }"
+        ));
+    }
+
+    #[test]
+    fn ternary_mapping_reads() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function richer(address a, address b) public view returns (address) {
+                    return balances[a] > balances[b] ? a : b;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "return Ok(
+                if self.data.balances.get(&a).unwrap_or_default()
+                    > self.data.balances.get(&b).unwrap_or_default()
+                {
+                    a
+                } else {
+                    b
+                },
+            )"
+        ));
+    }
+
+    #[test]
+    fn named_tuple_return() {
+        let output = transpile_source(
+            "contract pair {
+                mapping(address => uint256) x;
+                mapping(address => uint256) y;
+
+                function getPair(address u) external view returns (uint256 a, uint256 b) {
+                    a = x[u];
+                    b = y[u];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn get_pair(&self, u: AccountId) -> Result<(u128, u128), Error> {
+                let mut a = Default::default();
+                let mut b = Default::default();
+                a = self.data.x.get(&u).unwrap_or_default();
+                b = self.data.y.get(&u).unwrap_or_default();
+                Ok((a, b))
+            }"
+        ));
+    }
+
+    #[test]
+    fn payable_deposit() {
+        let output = transpile_source(
+            "contract bank {
+                mapping(address => uint256) deposits;
+
+                function deposit() public payable {
+                    deposits[msg.sender] += msg.value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(message, payable)] pub fn deposit(&mut self) -> Result<(), Error> {"
+        ));
+        assert!(contains_code(
+            &output,
+            "self.data.deposits.insert(
+                &self.env().caller(),
+                &(self.data.deposits.get(&self.env().caller()).unwrap_or_default()
+                    + self.env().transferred_value()),
+            );"
+        ));
+    }
+
+    #[test]
+    fn abstract_guarded_function() {
+        let output = transpile_source(
+            "abstract contract guarded {
+                address owner;
+
+                modifier onlyOwner() {
+                    require(msg.sender == owner, \"Not owner\");
+                    _;
+                }
+
+                function pause() public virtual onlyOwner;
+
+                function owned() public view returns (address) {
+                    return owner;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(message)]
+            #[modifiers(only_owner())]
+            pub fn pause(&mut self) -> Result<(), Error> {
+                todo!(\"Sol2Ink: abstract function, implement it in the inheriting contract\");"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub fn owned(&self) -> Result<AccountId, Error> { return Ok(self.data.owner) }"
+        ));
+
+        let output = transpile_source(
+            "abstract contract guarded {
+                address owner;
+
+                modifier onlyOwner() {
+                    require(msg.sender == owner, \"Not owner\");
+                    _;
+                }
+
+                function pause() public virtual onlyOwner;
+            }
+
+            contract pausable is guarded {
+                bool paused;
+
+                function pause() public override {
+                    paused = true;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(message)]
+            #[modifiers(only_owner())]
+            pub fn pause(&mut self) -> Result<(), Error> {
+                self.data.paused = true;
+                Ok(())
+            }"
+        ));
+    }
+
+    #[test]
+    fn mapping_ownership_require() {
+        let output = transpile_source(
+            "contract nft {
+                mapping(uint256 => address) owners;
+
+                function burn(uint256 tokenId) public {
+                    require(owners[tokenId] == msg.sender, \"not owner\");
+                    delete owners[tokenId];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "if self.data.owners.get(&token_id).unwrap_or_default() != self.env().caller() {
+                return Err(Error::Custom(String::from(\"not owner\")))
+            }"
+        ));
+    }
+
+    #[test]
+    fn explicit_function_shadows_getter() {
+        let output = transpile_source(
+            "contract owned {
+                address public owner;
+                uint256 public total;
+
+                function owner() public view returns (address) {
+                    return owner;
+                }
+            }",
+        );
+        assert_eq!(output.matches("pub fn owner(").count(), 1);
+        assert!(contains_code(
+            &output,
+            "pub fn total(&self) -> Result<u128, Error> { return Ok(self.data.total) }"
+        ));
+    }
+
+    #[test]
+    fn enumerable_set_todo() {
+        let output = transpile_source(
+            "contract registry {
+                using EnumerableSet for EnumerableSet.AddressSet;
+
+                EnumerableSet.AddressSet private members;
+                uint256 count;
+
+                function join() public {
+                    members.add(msg.sender);
+                    count += 1;
+                }
+            }",
+        );
+        assert!(contains_code(&output, "pub members: Vec<AccountId>,"));
+        assert!(contains_code(
+            &output,
+            "// Sol2Ink Not Implemented yet: members.add(msg.sender); EnumerableSet is not supported, use the `Vec` of values together with a `Mapping` of their positions
+            self.data.count += 1;"
+        ));
+    }
+
+    #[test]
+    fn override_widens_visibility() {
+        let output = transpile_source(
+            "contract child is base {
+                uint256 fee;
+
+                function _fee() public view override returns (uint256) {
+                    return fee;
+                }
+
+                function compute() external override(base) returns (uint256) {
+                    return _fee();
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(message)] pub fn fee(&self) -> Result<u128, Error> { return Ok(self.data.fee) }"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[ink(message)] pub fn compute(&mut self) -> Result<u128, Error> { return Ok(self.fee()?) }"
+        ));
+    }
+
+    #[test]
+    fn mapping_struct_field_increment() {
+        let output = transpile_source(
+            "contract vault {
+                struct Position {
+                    uint256 amount;
+                    uint256 since;
+                }
+
+                mapping(address => Position) positions;
+
+                function grow(address user, uint256 delta) public {
+                    positions[user].amount += delta;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[derive(Default, Clone, Encode, Decode)]"
+        ));
+        assert!(contains_code(
+            &output,
+            "let mut positions_entry = self.data.positions.get(&user).unwrap_or_default();
+            positions_entry.amount += delta;
+            self.data.positions.insert(&user, &(positions_entry));"
+        ));
+    }
+
+    #[test]
+    fn payable_constructor() {
+        let output = transpile_source(
+            "contract vault {
+                uint256 deposited;
+
+                constructor() payable {
+                    deposited = msg.value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(constructor, payable)]
+            pub fn new() -> Self {
+                ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                    instance.data.deposited = instance.env().transferred_value();
+                })
+            }"
+        ));
+    }
+
+    #[test]
+    fn typed_let_mapping_read() {
+        let output = transpile_source(
+            "contract bank {
+                mapping(address => uint256) balances;
+
+                function check(address user) public view returns (bool) {
+                    uint256 bal = balances[user];
+                    return bal > 0;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "let bal: u128 = self.data.balances.get(&user).unwrap_or_default();"
+        ));
+    }
+
+    #[test]
+    fn interface_return_type() {
+        let output = transpile_source(
+            "contract pool {
+                IERC20 stakeToken;
+
+                function token() external view returns (IERC20) {
+                    return stakeToken;
+                }
+            }",
+        );
+        assert!(contains_code(&output, "pub stake_token: AccountId,"));
+        assert!(contains_code(
+            &output,
+            "///Sol2Ink: the `IERC20` contract is returned as its `AccountId`
+            #[ink(message)]
+            pub fn token(&self) -> Result<AccountId, Error> {"
+        ));
+    }
+
+    #[test]
+    fn nested_mapping_write_with_caller() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => mapping(address => uint256)) allowances;
+
+                function approve(address spender, uint256 amount) public returns (bool) {
+                    allowances[msg.sender][spender] = amount;
+                    return true;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.data.allowances.insert(&(self.env().caller(), spender), &(amount));"
+        ));
+    }
+
+    #[test]
+    fn conditional_mapping_write() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function setBalance(address user, uint256 value) public {
+                    if (balances[user] != value) balances[user] = value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "if self.data.balances.get(&user).unwrap_or_default() != value {
+                self.data.balances.insert(&user, &(value));
+            }"
+        ));
+    }
+
+    #[test]
+    fn enum_member_access_in_else_if_chain() {
+        let config = Config {
+            normalize_enum_variants: true,
+            ..Default::default()
+        };
+        let output = transpile_source_with_config(
+            "contract calc {
+                enum oper { add, sub, mul }
+
+                struct Pair {
+                    uint64 add;
+                    uint64 sub;
+                }
+
+                Pair pair;
+
+                function op(oper o, uint64 a, uint64 b) public view returns (uint64) {
+                    if (o == oper.add) {
+                        return a + b + pair.add;
+                    } else if (o == oper.sub) {
+                        return a - b - pair.sub;
+                    } else if (o == oper.mul) {
+                        return a * b;
+                    }
+                    return 0;
+                }
+            }",
+            &config,
+        );
+        assert!(output.contains("if o == Oper::Add {"));
+        assert!(output.contains("} else if o == Oper::Sub {"));
+        assert!(output.contains("} else if o == Oper::Mul {"));
+        assert!(output.contains("return Ok(a + b + self.data.pair.add)"));
+        assert!(output.contains("return Ok(a - b - self.data.pair.sub)"));
+    }
+
+    #[test]
+    fn dynamic_array_built_in_loop() {
+        let output = transpile_source(
+            "contract arr {
+                uint256 n;
+
+                function getAll() public view returns (uint256[] memory) {
+                    uint256[] memory r = new uint256[](n);
+                    for (uint256 i = 0; i < n; i++) {
+                        r[i] = i * 2;
+                    }
+                    return r;
+                }
+            }",
+        );
+        assert!(
+            output.contains("let mut r: Vec<u128> = vec![u128::default(); self.data.n as usize];")
+        );
+        assert!(output.contains("r[i as usize] = i * 2;"));
+        assert!(output.contains("return Ok(r)"));
+    }
+
+    #[test]
+    fn enum_type_names_normalized() {
+        let output = transpile_source(
+            "contract calc {
+                enum oper { add, sub }
+
+                oper[] history;
+                mapping(address => oper) last;
+
+                function record(oper op, bytes5 tag) public returns (oper) {
+                    last[msg.sender] = op;
+                    return op;
+                }
+            }",
+        );
+        assert!(output.contains("pub history: Vec<Oper>,"));
+        assert!(output.contains("pub last: Mapping<AccountId, Oper>,"));
+        assert!(contains_code(
+            &output,
+            "pub fn record(&mut self, op: Oper, tag: [u8; 5]) -> Result<Oper, Error> {"
+        ));
+    }
+
+    #[test]
+    fn mapping_read_by_address_param() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+
+                function balanceOf(address owner) public view returns (uint256) {
+                    return balances[owner];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn balance_of(&self, owner: AccountId) -> Result<u128, Error> {
+                return Ok(self.data.balances.get(&owner).unwrap_or_default())
+            }"
+        ));
+    }
+
+    #[test]
+    fn revert_with_message() {
+        let output = transpile_source(
+            "contract vault {
+                error TooLow(uint256 amount);
+                uint256 total;
+
+                function withdraw(uint256 amount) public {
+                    if (amount > total) {
+                        revert(\"Vault: insufficient\");
+                    } else if (amount == 0) {
+                        revert TooLow(amount);
+                    } else {
+                        revert();
+                    }
+                }
+            }",
+        );
+        assert!(output.contains("return Err(Error::Custom(String::from(\"Vault: insufficient\")))"));
+        assert!(output.contains("return Err(Error::TooLow(amount))"));
+        assert!(output.contains("return Err(Error::Custom(String::new()))"));
+        assert!(!output.contains("revert()"));
+    }
+
+    #[test]
+    fn revert_in_terminal_else() {
+        let output = transpile_source(
+            "contract primitives {
+                enum oper { add, sub, mul }
+
+                function op_u64(oper op, uint64 a, uint64 b) pure public returns (uint64) {
+                    if (op == oper.add) {
+                        return a + b;
+                    } else if (op == oper.sub) {
+                        return a - b;
+                    } else if (op == oper.mul) {
+                        return a * b;
+                    } else {
+                        revert();
+                    }
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "} else if op == Oper::mul {
+                return Ok(a * b)
+            } else {
+                return Err(Error::Custom(String::new()));
+            }
+        }"
+        ));
+        assert!(!output.contains("todo!()"));
+    }
+
+    #[test]
+    fn fallback_only_for_untranslated_bodies() {
+        let output = transpile_source(
+            "contract c {
+                uint256 x;
+
+                function translated() public view returns (uint256) {
+                    return x;
+                }
+
+                function untranslated() public view returns (uint256) {
+                    x.doSomething{value: 1}();
+                }
+
+                function empty() public view returns (uint256) {}
+
+                function reverts() public {
+                    revert(\"no\");
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn translated(&self) -> Result<u128, Error> {
+                return Ok(self.data.x)
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "// Sol2Ink Not Implemented yet: ();
+            todo!()
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub fn empty(&self) -> Result<u128, Error> { todo!() }"
+        ));
+        assert!(contains_code(
+            &output,
+            "return Err(Error::Custom(String::from(\"no\")));
+            }"
+        ));
+        assert_eq!(output.matches("todo!()").count(), 2);
+    }
+
+    #[test]
+    fn mapping_read_then_delete() {
+        let output = transpile_source(
+            "contract c {
+                mapping(address => uint256) balances;
+
+                function take(address u) public returns (uint256) {
+                    uint256 v = balances[u];
+                    delete balances[u];
+                    return v;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "let v: u128 = self.data.balances.get(&u).unwrap_or_default();
+            self.data.balances.remove(&u);
+            return Ok(v)"
+        ));
+    }
+
+    #[test]
+    fn global_using_for_directive() {
+        let output = transpile_source(
+            "contract c {
+                using SafeMath for *;
+                uint256 total;
+
+                function add(uint256 a) public {
+                    total = total.add(a);
+                }
+            }",
+        );
+        assert!(!output.contains("using"));
+        assert!(output.contains("pub total: u128,"));
+        assert!(contains_code(
+            &output,
+            "self.data.total = todo!(
+                \"Sol2Ink Not Implemented yet: total.add(a) calls SafeMath.add, libraries are not translated\"
+            );"
+        ));
+    }
+
+    #[test]
+    fn overloaded_functions_renamed() {
+        let output = transpile_source(
+            "interface IERC721 {
+                function safeTransferFrom(address from, address to, uint256 tokenId, bytes calldata data) external;
+
+                function safeTransferFrom(address from, address to, uint256 tokenId) external;
+            }",
+        );
+        assert!(output.contains("fn safe_transfer_from_with_data("));
+        assert_eq!(output.matches("fn safe_transfer_from(").count(), 1);
+
+        let output = transpile_source(
+            "contract calc {
+                function add(uint256 a) public pure returns (uint256) {
+                    return a;
+                }
+
+                function add(uint256 a, uint256 b) public pure returns (uint256) {
+                    return a + b;
+                }
+
+                function add(uint256 a, address b) public pure returns (uint256) {
+                    return a;
+                }
+            }",
+        );
+        assert!(output.contains("pub fn add(&self, a: u128) -> Result<u128, Error> {"));
+        assert!(contains_code(
+            &output,
+            "pub fn add_with_b(&self, a: u128, b: u128) -> Result<u128, Error> {"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub fn add2(&self, a: u128, b: AccountId) -> Result<u128, Error> {"
+        ));
+    }
+
+    #[test]
+    fn mapping_storage_fields() {
+        let output = transpile_source(
+            "contract token {
+                mapping(address => uint256) balances;
+                mapping(address => mapping(uint256 => bool)) approved;
+            }",
+        );
+        assert!(contains_code(&output, "use openbrush::{ storage::Mapping,"));
+        assert!(output.contains("pub balances: Mapping<AccountId, u128>,"));
+        assert!(output.contains("pub approved: Mapping<(AccountId, u128), bool>,"));
+    }
+
+    #[test]
+    fn mapping_read_in_logical_expression() {
+        let output = transpile_source(
+            "contract c {
+                mapping(address => bool) approved;
+                bool paused;
+                uint256 count;
+
+                function act(address op) public {
+                    require(approved[op] && !paused, \"not allowed\");
+                    if (approved[op] && !paused) {
+                        count += 1;
+                    }
+                }
+            }",
+        );
+        assert!(output.contains(
+            "if !(self.data.approved.get(&op).unwrap_or_default() && !self.data.paused) {"
+        ));
+        assert!(output
+            .contains("if self.data.approved.get(&op).unwrap_or_default() && !self.data.paused {"));
+    }
+
+    #[test]
+    fn byte_array_bitwise_operations() {
+        let output = transpile_source(
+            "contract primitives {
+                function shift5(bytes5 a, uint64 r) public pure returns (bytes5) {
+                    return a << r;
+                }
+
+                function shift14(bytes14 a, uint64 r) public pure returns (bytes14) {
+                    return a >> r;
+                }
+
+                function xor5(bytes5 a, bytes5 b) public pure returns (bytes5) {
+                    return a ^ b;
+                }
+
+                function or14(bytes14 a, bytes14 b) public pure returns (bytes14) {
+                    return a | b;
+                }
+            }",
+        );
+        assert!(output
+            .contains("fn shl_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {"));
+        assert!(output
+            .contains("fn shr_bytes<const N: usize>(value: [u8; N], shift: u128) -> [u8; N] {"));
+        assert!(output.contains("return Ok(shl_bytes(a, (r as u128)))"));
+        assert!(output.contains("return Ok(shr_bytes(a, (r as u128)))"));
+        assert!(output.contains("return Ok(xor_bytes(a, b))"));
+        assert!(output.contains("return Ok(or_bytes(a, b))"));
+        assert!(!output.contains("fn and_bytes"));
+    }
+
+    #[test]
+    fn return_name_colliding_with_param() {
+        let output = transpile_source(
+            "contract c {
+                function scale(uint256 amount) public pure returns (uint256 amount) {
+                    amount = amount * 2;
+                }
+            }",
+        );
+        assert!(output.contains("pub fn scale(&self, amount: u128) -> Result<u128, Error> {"));
+        assert!(output.contains("let mut amount_out = Default::default();"));
+        assert!(output.contains("Ok(amount_out)"));
+    }
+
+    #[test]
+    fn string_literal_returned_as_fixed_bytes() {
+        let output = transpile_source(
+            "contract primitives {
+                function return_u8_6() public pure returns (bytes6) {
+                    return \"ABCDEF\";
+                }
+
+                function return_short() public pure returns (bytes6) {
+                    return \"ABC\";
+                }
+            }",
+        );
+        assert!(output.contains("return Ok(*b\"ABCDEF\")"));
+        assert!(contains_code(
+            &output,
+            "// Sol2Ink: the string literal \"ABC\" is right-padded with zero bytes to 6 bytes
+            return Ok([0x41, 0x42, 0x43, 0x00, 0x00, 0x00])"
+        ));
+    }
+
+    #[test]
+    fn mapping_key_from_struct_field() {
+        let output = transpile_source(
+            "contract c {
+                struct Order {
+                    uint256 id;
+                    uint256 amount;
+                }
+
+                mapping(address => mapping(uint256 => uint256)) orders;
+
+                function get(Order memory order) public view returns (uint256) {
+                    return orders[msg.sender][order.id];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.data.orders.get(&(self.env().caller(), order.id)).unwrap_or_default()"
+        ));
+    }
+
+    #[test]
+    fn u256_integer_mapping() {
+        let source = "contract big {
+                uint256 total;
+                int256 delta;
+
+                function grow(uint256 amount, uint8 exp) public returns (uint256) {
+                    total = total ** exp;
+                    uint256 scaled = uint256(amount);
+                    return scaled + total;
+                }
+            }";
+        let output = transpile_source(source);
+        assert!(output.contains("pub total: u128,"));
+        assert!(!output.contains("U256"));
+
+        let config = Config {
+            u256: true,
+            ..Default::default()
+        };
+        let output = transpile_source_with_config(source, &config);
+        assert!(output.contains("use primitive_types::U256;"));
+        assert!(output.contains("pub total: U256,"));
+        assert!(output.contains("pub delta: i128,"));
+        assert!(output.contains("amount: U256, exp: u8) -> Result<U256, Error>"));
+        assert!(contains_code(
+            &output,
+            "self.data.total = self.data.total.pow(U256::from(exp));"
+        ));
+        assert!(contains_code(
+            &output,
+            "let scaled: U256 = U256::from(amount);"
+        ));
+    }
+
+    #[test]
+    fn u256_literals_and_lengths() {
+        let source = "contract big {
+    uint256 total;
+    uint256[] items;
+    int256 delta;
+
+    function sum(uint256 x, uint8 small) public returns (uint256) {
+        total = 0;
+        total += x;
+        total += small;
+        if (total > 100) {
+            return 1;
+        }
+        return items.length + total;
+    }
+}";
+        let config = Config {
+            u256: true,
+            ..Default::default()
+        };
+        let (output, diagnostics) = transpile(source, &config).unwrap();
+        let output = file_utils::format_tokens(output);
+        assert!(contains_code(
+            &output,
+            "self.data.total = U256::from(0);
+            self.data.total += x;
+            self.data.total += U256::from(small);
+            if self.data.total > U256::from(100) {
+                return Ok(U256::from(1))
+            }
+            return Ok(U256::from(self.data.items.len()) + self.data.total)"
+        ));
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].location.line, 4);
+        assert!(diagnostics[0]
+            .message
+            .contains("int256 is translated to i128"));
+    }
+
+    #[test]
+    fn base_internal_function_called_from_child() {
+        let output = transpile_source(
+            "abstract contract Base {
+                uint256 total;
+
+                function _bump(uint256 amount) internal {
+                    total += amount;
+                }
+            }
+
+            contract Child is Base {
+                function deposit(uint256 amount) public {
+                    _bump(amount);
+                }
+            }",
+        );
+        assert!(output.contains("pub struct Child {"));
+        assert!(output.contains("pub total: u128,"));
+        assert!(contains_code(
+            &output,
+            "fn _bump(&mut self, amount: u128) -> Result<(), Error> {
+                self.data.total += amount;
+                Ok(())
+            }"
+        ));
+        assert!(contains_code(&output, "self._bump(amount)?;"));
+    }
+
+    #[test]
+    fn contract_not_inherited_is_emitted() {
+        let source = "abstract contract Base {
+            address owner;
+
+            modifier onlyOwner() {
+                require(msg.sender == owner, \"Not owner\");
+                _;
+            }
+
+            function _guarded() internal onlyOwner {}
+        }
+
+        contract Child is Base {
+            function run() public {
+                _guarded();
+            }
+        }";
+        let output = transpile_source(source);
+        assert!(output.contains("pub mod child {"));
+        assert!(contains_code(
+            &output,
+            "use openbrush::{ modifier_definition, modifiers, traits::Storage, };"
+        ));
+
+        let config = Config::default();
+        let with_other = format!("{source} contract Other {{ bool flag; }}");
+        assert_eq!(
+            transpile(&with_other, &config).map(|_| ()),
+            Err(ParserError::MultipleContracts(vec![
+                String::from("Child"),
+                String::from("Other")
+            ]))
+        );
+    }
+
+    #[test]
+    fn message_and_block_globals() {
+        let output = transpile_source(
+            "contract clock {
+                uint256 lastUpdate;
+                uint256 lastBlock;
+                address lastCaller;
+                uint256 lastValue;
+
+                constructor() {
+                    lastUpdate = block.timestamp;
+                }
+
+                function touch() public payable {
+                    lastUpdate = block.timestamp;
+                    lastBlock = block.number;
+                    lastCaller = msg.sender;
+                    lastValue = msg.value;
+                }
+
+                function input() public view returns (bytes memory) {
+                    return msg.data;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "instance.data.last_update = instance.env().block_timestamp();"
+        ));
+        assert!(contains_code(
+            &output,
+            "self.data.last_update = self.env().block_timestamp();
+            self.data.last_block = self.env().block_number();
+            self.data.last_caller = self.env().caller();
+            self.data.last_value = self.env().transferred_value();"
+        ));
+        assert!(output.contains("Sol2Ink Not Implemented yet: msg.data"));
+    }
+
+    #[test]
+    fn signed_mapping_value_read() {
+        let source = "contract ledger {
+                mapping(address => int256) balances;
+
+                function balanceOf(address owner) public view returns (int256) {
+                    return balances[owner];
+                }
+            }";
+        let config = Config {
+            u256: true,
+            ..Default::default()
+        };
+        for output in [
+            transpile_source(source),
+            transpile_source_with_config(source, &config),
+        ] {
+            assert!(output.contains("pub balances: Mapping<AccountId, i128>,"));
+            assert!(output.contains("-> Result<i128, Error>"));
+            assert!(contains_code(
+                &output,
+                "return Ok(self.data.balances.get(&owner).unwrap_or_default())"
+            ));
+        }
+    }
+
+    #[test]
+    fn integer_shift_modes() {
+        let source = "contract bits {
+                function op_u_64(uint64 a, uint64 b) public pure returns (uint64) {
+                    return a << b;
+                }
+
+                function shr(uint64 a, uint64 b) public pure returns (uint64) {
+                    return a >> b;
+                }
+
+                function bytes_shift(bytes5 a, uint64 r) public pure returns (bytes5) {
+                    return a << r;
+                }
+            }";
+        let output = transpile_source(source);
+        assert!(output.contains("return Ok(a << b)"));
+        assert!(output.contains("return Ok(a >> b)"));
+        assert!(!output.contains("wrapping_"));
+
+        let config = Config {
+            wrapping_shifts: true,
+            ..Default::default()
+        };
+        let output = transpile_source_with_config(source, &config);
+        assert!(output.contains("return Ok(a.wrapping_shl((b as u32)))"));
+        assert!(output.contains("return Ok(a.wrapping_shr((b as u32)))"));
+        assert!(output.contains("return Ok(shl_bytes(a, (r as u128)))"));
+    }
+
+    #[test]
+    fn require_guards() {
+        let output = transpile_source(
+            "contract guard {
+                address owner;
+                uint256 total;
+
+                constructor(uint256 value) {
+                    require(value > 0);
+                    total = value;
+                }
+
+                function set(uint256 value) public {
+                    require(msg.sender == owner, \"Caller is not the owner\");
+                    require(value > 0 && value < 100);
+                    require(!(value == 2 && total == 3));
+                    total = value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "if value <= 0 {
+                panic!()
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "if self.env().caller() != self.data.owner {
+                return Err(Error::Custom(String::from(\"Caller is not the owner\")))
+            }
+            if !(value > 0 && value < 100) {
+                return Err(Error::Custom(String::new()))
+            }
+            if value == 2 && self.data.total == 3 {
+                return Err(Error::Custom(String::new()))
+            }
+            self.data.total = value;"
+        ));
+    }
+
+    #[test]
+    fn emit_transfer_event() {
+        let output = transpile_source(
+            "contract token {
+                event Transfer(address indexed from, address indexed to, uint256 value);
+
+                function send(address to, uint256 amount) public {
+                    emit Transfer(msg.sender, to, amount);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[ink(event)]
+            pub struct Transfer {
+                #[ink(topic)]
+                from: AccountId,
+                #[ink(topic)]
+                to: AccountId,
+                value: u128,
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "self.env().emit_event(Transfer {
+                from: self.env().caller(),
+                to,
+                value: amount,
+            });"
+        ));
+    }
+
+    #[test]
+    fn mapping_value_postfix_increment() {
+        let output = transpile_source(
+            "contract counter {
+                mapping(address => uint256) ids;
+                uint256 count;
+
+                function bump(address owner) public {
+                    ids[owner]++;
+                    --ids[owner];
+                    count++;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.data.ids.insert(&owner, &(self.data.ids.get(&owner).unwrap_or_default() + 1));
+            self.data.ids.insert(&owner, &(self.data.ids.get(&owner).unwrap_or_default() - 1));
+            self.data.count += 1;"
+        ));
+    }
+
+    #[test]
+    fn natspec_doc_sections() {
+        let output = transpile_source(
+            "contract token {
+                /**
+                 * @notice Moves tokens to another account
+                 * @dev Emits a transfer event,
+                 * reverts when the balance is too low.
+                 * @param to The receiver
+                 * @param tokenAmount The amount
+                 * of tokens to move
+                 * @return whether the transfer succeeded
+                 */
+                function send(address to, uint256 tokenAmount) public returns (bool) {
+                    return true;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "/// Moves tokens to another account
+            /// Emits a transfer event,
+            /// reverts when the balance is too low.
+            ///
+            /// # Parameters
+            /// - `to`: The receiver
+            /// - `token_amount`: The amount of tokens to move
+            ///
+            /// # Returns
+            /// - whether the transfer succeeded
+            #[ink(message)]"
+        ));
+    }
+
+    #[test]
+    fn return_this_left_as_todo() {
+        let output = transpile_source(
+            "contract builder {
+                uint256 value;
+
+                function setValue(uint256 newValue) public returns (builder) {
+                    value = newValue;
+                    return this;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.data.value = new_value;
+            // Sol2Ink Not Implemented yet: return this; ink! contracts can not return themselves, consider returning `self.env().account_id()`
+            todo!()"
+        ));
+        assert!(!output.contains("Ok(this)"));
+    }
+
+    #[test]
+    fn macro_namespace_toggle() {
+        let contract = "contract Minter is AccessControl {
+                bytes32 public constant MINTER_ROLE = keccak256(\"MINTER_ROLE\");
+                mapping(address => uint256) balances;
+
+                function mint(address to) public onlyRole(MINTER_ROLE) {
+                    balances[to] = 1;
+                }
+            }";
+        let interface = "interface IToken {
+                function balanceOf(address owner) external view returns (uint256);
+            }";
+
+        for namespace in ["openbrush", "brush"] {
+            let config = Config {
+                macro_namespace: String::from(namespace),
+                ..Default::default()
+            };
+            let output = transpile_source_with_config(contract, &config);
+            assert!(output.contains(&format!("#[{namespace}::contract]")));
+            assert!(output.contains(&format!("{namespace}::storage_unique_key!(Data)")));
+            assert!(output.contains(&format!("#[{namespace}::upgradeable_storage(STORAGE_KEY)]")));
+            assert!(output.contains(&format!("use {namespace}::{{")));
+            // no path is left in the other namespace
+            if namespace == "brush" {
+                assert!(!output.contains("openbrush::"));
+            } else {
+                assert_eq!(
+                    output.matches("brush::").count(),
+                    output.matches("openbrush::").count()
+                );
+            }
+
+            let toml = toml_builder::generate_cargo_toml(
+                &toml_builder::openbrush_features(&output),
+                false,
+                namespace,
+            );
+            assert!(toml.contains(&format!("\"{namespace}/std\"")));
+            assert!(toml.contains("features = [\"access_control\"]"));
+
+            let output = transpile_source_with_config(interface, &config);
+            assert!(output.contains(&format!("#[{namespace}::wrapper]")));
+            assert!(output.contains(&format!("#[{namespace}::trait_definition]")));
+        }
+        assert!(toml_builder::generate_cargo_toml(&[], false, "brush")
+            .contains("brush = { package = \"openbrush\", version"));
+    }
+
+    #[test]
+    fn mapping_seeded_from_array_literal() {
+        let output = transpile_source(
+            "contract rates {
+                mapping(uint256 => uint256) rates;
+
+                constructor() {
+                    for (uint256 i = 0; i < 3; i++) {
+                        rates[i] = [10, 20, 30][i];
+                    }
+                    uint256[3] memory seeds = [uint256(40), 50, 60];
+                    rates[3] = seeds[1];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "instance.data.rates.insert(&i, &([10, 20, 30][i as usize]));"
+        ));
+        assert!(contains_code(
+            &output,
+            "let seeds: [u128; 3] = [(40 as u128), 50, 60];
+            instance.data.rates.insert(&3, &(seeds[1 as usize]));"
+        ));
+    }
+
+    #[test]
+    fn chained_immutable_initialization() {
+        let output = transpile_source(
+            "contract pool {
+                IFactory public immutable factory;
+                uint256 public immutable fee;
+
+                constructor(address _factory) {
+                    factory = IFactory(_factory);
+                    fee = factory.defaultFee();
+                }
+
+                function refresh() public returns (uint256) {
+                    return factory.defaultFee();
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "instance.data.factory = factory;
+            instance.data.fee = FactoryRef::default_fee(&instance.data.factory).unwrap();"
+        ));
+        assert!(output.contains("return Ok(FactoryRef::default_fee(&self.data.factory)?)"));
+    }
+
+    #[test]
+    fn only_owner_modifier() {
+        let output = transpile_source(
+            "contract vault {
+                address owner;
+                uint256 total;
+                bool locked;
+
+                modifier onlyOwner() {
+                    require(msg.sender == owner, \"Not owner\");
+                    _;
+                }
+
+                modifier nonReentrant() {
+                    require(!locked, \"Reentrant call\");
+                    locked = true;
+                    _;
+                    locked = false;
+                }
+
+                function set(uint256 value) public onlyOwner nonReentrant {
+                    total = value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[modifier_definition]
+            pub fn only_owner<F, R>(instance: &mut vault, body: F) -> Result<R, Error>
+            where
+                F: FnOnce(&mut vault) -> Result<R, Error>,
+            {
+                if instance.env().caller() != instance.data.owner {
+                    return Err(Error::Custom(String::from(\"Not owner\")))
+                }
+                body(instance)
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "instance.data.locked = true;
+            let result = body(instance)?;
+            instance.data.locked = false;
+            Ok(result)"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[modifiers(only_owner())]
+            #[modifiers(non_reentrant())]
+            pub fn set(&mut self, value: u128) -> Result<(), Error> {"
+        ));
+    }
+
+    #[test]
+    fn modifier_placeholder_position() {
+        let output = transpile_source(
+            "contract vault {
+                uint256 x;
+                uint256 calls;
+
+                modifier guard() {
+                    require(x > 0, \"zero\");
+                }
+
+                modifier counted() {
+                    _;
+                    calls += 1;
+                }
+
+                function set(uint256 value) public guard counted {
+                    x = value;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[modifier_definition]
+            pub fn guard<F, R>(instance: &mut vault, body: F) -> Result<R, Error>
+            where
+                F: FnOnce(&mut vault) -> Result<R, Error>,
+                R: Default,
+            {
+                if instance.data.x <= 0 {
+                    return Err(Error::Custom(String::from(\"zero\")))
+                }
+                Ok(Default::default())
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "#[modifier_definition]
+            pub fn counted<F, R>(instance: &mut vault, body: F) -> Result<R, Error>
+            where
+                F: FnOnce(&mut vault) -> Result<R, Error>,
+            {
+                let result = body(instance)?;
+                instance.data.calls += 1;
+                Ok(result)
+            }"
+        ));
+    }
+
+    #[test]
+    fn ownable_base() {
+        let output = transpile_source(
+            "contract Vault is Ownable {
+                uint256 limit;
+
+                function setLimit(uint256 newLimit) public onlyOwner {
+                    limit = newLimit;
+                }
+
+                function handOver(address account) public {
+                    transferOwnership(account);
+                }
+            }",
+        );
+        assert!(output.contains("contracts::ownable::*"));
+        assert!(output.contains("OwnableError(OwnableError),"));
+        assert!(contains_code(
+            &output,
+            "#[derive(Default, SpreadAllocate, Storage)] pub struct Vault { #[storage_field] data: Data, #[storage_field] ownable: ownable::Data, }"
+        ));
+        assert!(output.contains("impl Ownable for Vault {}"));
+        assert!(output.contains("instance._init_with_owner(instance.env().caller());"));
+        assert!(contains_code(
+            &output,
+            "#[modifiers(only_owner())] pub fn set_limit(&mut self, new_limit: u128)"
+        ));
+        assert!(output.contains("self.transfer_ownership(account)?;"));
+    }
+
+    #[test]
+    fn cast_mapping_read() {
+        let output = transpile_source(
+            "contract Flags {
+                mapping(address => uint256) flags;
+
+                function flagOf(address user) public view returns (uint8) {
+                    return uint8(flags[user]);
+                }
+            }",
+        );
+        assert!(
+            output.contains("return Ok((self.data.flags.get(&user).unwrap_or_default() as u8))")
+        );
+    }
+
+    #[test]
+    fn error_enum_variants() {
+        let output = transpile_source(
+            "contract Counter is Ownable {
+                error TooHigh(uint256 value);
+                error Empty();
+                uint256 count;
+
+                function set(uint256 value) public {
+                    count = value;
+                }
+            }",
+        );
+        assert!(output.contains("use ink_prelude::string::String;"));
+        assert!(contains_code(
+            &output,
+            "#[derive(Debug, Encode, Decode, PartialEq)]
+            #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))]
+            pub enum Error {
+                Custom(String),
+                TooHigh(u128),
+                Empty,
+                OwnableError(OwnableError),
+            }"
+        ));
+    }
+
+    #[test]
+    fn untranslated_statement_locations() {
+        let source = "contract Chain {
+    uint256 value;
+
+    function chain() public returns (Chain) {
+        value = 1;
+        return this;
+    }
+
+    function input() public view returns (bytes memory) {
+        if (value > 0) {
+            return msg.data;
+        }
+    }
+}";
+        let config = Config {
+            inline_locations: true,
+            ..Default::default()
+        };
+        let (output, diagnostics) = transpile(source, &config).unwrap();
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.location.to_string())
+                .collect::<Vec<_>>(),
+            vec!["L6:C9", "L11:C13"]
+        );
+        assert!(diagnostics[1].message.contains("msg.data"));
+        assert!(file_utils::format_tokens(output)
+            .contains("consider returning `self.env().account_id()` // L6:C9"));
+    }
+
+    #[test]
+    fn address_types() {
+        let output = transpile_source(
+            "contract Registry {
+                struct Member {
+                    address account;
+                    address payable wallet;
+                }
+                address[] accounts;
+                address payable treasury;
+                mapping(address => Member) members;
+
+                function join(address payable wallet, address[] memory others) public {
+                    address payable previous = treasury;
+                    treasury = payable(wallet);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub struct Member { account: AccountId, wallet: AccountId, }"
+        ));
+        assert!(output.contains("pub accounts: Vec<AccountId>,"));
+        assert!(output.contains("pub treasury: AccountId,"));
+        assert!(output.contains("pub members: Mapping<AccountId, Member>,"));
+        assert!(contains_code(
+            &output,
+            "#[ink(message)] pub fn join(&mut self, wallet: AccountId, others: Vec<AccountId>)"
+        ));
+        assert!(output.contains("let previous: AccountId = self.data.treasury;"));
+        assert!(output.contains("self.data.treasury = wallet;"));
+    }
+
+    #[test]
+    fn tuple_literal_return() {
+        let output = transpile_source(
+            "contract Pair {
+                uint256 reserve0;
+                uint256 reserve1;
+
+                function getReserves() public view returns (uint256, uint256) {
+                    return (reserve0, reserve1 * 2);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn get_reserves(&self) -> Result<(u128, u128), Error> {
+                return Ok((self.data.reserve0, self.data.reserve1 * 2))
+            }"
+        ));
+    }
+
+    #[test]
+    fn nested_mapping_getter() {
+        let output = transpile_source(
+            "contract Permissions {
+                mapping(uint256 => mapping(address => mapping(uint8 => bool))) public flags;
+
+                function anyFlag(uint256 id) public view returns (bool) {
+                    return flags[id];
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn flags(&self, key0: u128, key1: AccountId, key2: u8) -> Result<bool, Error> {
+                return Ok(self.data.flags.get(&(key0, key1, key2)).unwrap_or_default())
+            }"
+        ));
+        assert!(contains_code(
+            &output,
+            "return Ok(todo!(\"Sol2Ink Not Implemented yet: flags indexed by 1 of 3 keys\"))"
+        ));
+    }
+
+    #[test]
+    fn counting_for_loops() {
+        let output = transpile_source(
+            "contract Sums {
+                uint256 total;
+
+                function sum(uint256 n) public {
+                    for (uint256 i = 0; i < n; i++) {
+                        total += i;
+                    }
+                    for (uint256 j = 0; j <= n; j += 2) {
+                        total += j;
+                    }
+                    for (uint256 k = 0; k < n; k++) {
+                        n -= 1;
+                    }
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "for i in 0..n { self.data.total += i; }"
+        ));
+        assert!(contains_code(
+            &output,
+            "for j in (0..=n).step_by(2) { self.data.total += j; }"
+        ));
+        assert!(contains_code(
+            &output,
+            "let mut k: u128 = 0; while k < n { n -= 1; k += 1; }"
+        ));
+    }
+
+    #[test]
+    fn local_struct_array() {
+        let output = transpile_source(
+            "contract Book {
+                struct Order {
+                    address maker;
+                    uint256 amount;
+                }
+
+                function firstAmount(uint256 n) public view returns (uint256) {
+                    Order[] memory orders = new Order[](n);
+                    orders[0].amount = 5;
+                    return orders[0].amount;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "#[derive(Default, Clone, Encode, Decode)] #[cfg_attr(feature = \"std\", derive(scale_info::TypeInfo))] pub struct Order"
+        ));
+        assert!(output.contains("let mut orders: Vec<Order> = vec![Order::default(); n as usize];"));
+        assert!(output.contains("orders[0 as usize].amount = 5;"));
+    }
+
+    #[test]
+    fn do_while_with_break() {
+        let output = transpile_source(
+            "contract Counter {
+                uint256 total;
+
+                function count(uint256 n) public {
+                    uint256 i = 0;
+                    do {
+                        i++;
+                        if (i == 5) {
+                            break;
+                        }
+                        if (i == 2) continue;
+                        total += i;
+                    } while (i < n);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "loop {
+                i += 1;
+                if i == 5 {
+                    break;
+                }
+                if i == 2 {
+                    if !(i < n) {
+                        break;
+                    }
+                    continue;
+                }
+                self.data.total += i;
+                if !(i < n) {
+                    break;
+                }
+            }"
+        ));
+    }
+
+    #[test]
+    fn contract_address_and_balance() {
+        let output = transpile_source(
+            "contract Escrow {
+                uint256 reserved;
+
+                function vault() public view returns (address) {
+                    return address(this);
+                }
+
+                function available() public view returns (uint256) {
+                    return address(this).balance - reserved;
+                }
+
+                function funded() public view returns (bool) {
+                    return this.balance > 0;
+                }
+
+                function toAddress(uint160 value) public view returns (address) {
+                    return address(value);
+                }
+
+                function shifted(uint160 value) public view returns (address) {
+                    uint160 raw = value + 1;
+                    return address(raw);
+                }
+            }",
+        );
+        assert!(output.contains("return Ok(self.env().account_id())"));
+        assert!(output.contains("return Ok(self.env().balance() - self.data.reserved)"));
+        assert!(output.contains("return Ok(self.env().balance() > 0)"));
+        assert!(
+            output.contains("return Ok(todo!(\"Sol2Ink Not Implemented yet: address(value)\"))")
+        );
+        assert!(output.contains("return Ok(todo!(\"Sol2Ink Not Implemented yet: address(raw)\"))"));
+    }
+
+    #[test]
+    fn require_with_custom_error() {
+        let output = transpile_source(
+            "contract Sale {
+                enum State { Pending, Active }
+                error NotActive();
+                error TooLow(uint256 amount);
+                State state;
+
+                function buy(uint256 amount) public {
+                    require(state == State.Active, NotActive());
+                    require(amount > 10, TooLow(amount));
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "if self.data.state != State::Active { return Err(Error::NotActive); }"
+        ));
+        assert!(contains_code(
+            &output,
+            "if amount <= 10 { return Err(Error::TooLow(amount)); }"
+        ));
+    }
+
+    #[test]
+    fn string_type() {
+        let output = transpile_source(
+            "contract Named {
+                string name;
+
+                function setName(string memory newName) public {
+                    name = newName;
+                }
+
+                function getName() public view returns (string memory) {
+                    return name;
+                }
+            }",
+        );
+        assert!(output.contains("use ink_prelude::string::String;"));
+        assert!(output.contains("pub name: String,"));
+        assert!(contains_code(
+            &output,
+            "pub fn set_name(&mut self, new_name: String) -> Result<(), Error> { self.data.name = new_name; Ok(()) }"
+        ));
+        assert!(contains_code(
+            &output,
+            "pub fn get_name(&self) -> Result<String, Error> { return Ok(self.data.name.clone()) }"
+        ));
+    }
+
+    #[test]
+    fn emit_mapping_read() {
+        let output = transpile_source(
+            "contract Token {
+                event Sync(address indexed account, uint256 balance);
+                mapping(address => uint256) balances;
+
+                function sync(address account) public {
+                    emit Sync(account, balances[account]);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "self.env().emit_event(Sync { account, balance: self.data.balances.get(&account).unwrap_or_default(), });"
+        ));
+    }
+
+    #[test]
+    fn data_locations_removed() {
+        let output = transpile_source(
+            "contract Pools {
+                struct MemoryPool {
+                    uint256 size;
+                }
+                MemoryPool pool;
+
+                function resize(
+                    uint256[] memory sizes,
+                    MemoryPool calldata template,
+                    uint256 memoryLimit
+                ) public returns (uint256[] memory, MemoryPool memory) {
+                    uint256[] memory copy = sizes;
+                    MemoryPool storage current = pool;
+                    require(memoryLimit > 0, \"out of memory\");
+                    return (copy, template);
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn resize(&mut self, sizes: Vec<u128>, template: MemoryPool, memory_limit: u128,) -> Result<(Vec<u128>, MemoryPool), Error>"
+        ));
+        assert!(output.contains("let copy: Vec<u128> = sizes;"));
+        assert!(output.contains("let current: MemoryPool = self.data.pool;"));
+        assert!(output.contains("String::from(\"out of memory\")"));
+    }
+
+    #[test]
+    fn proxy_fallback_advice() {
+        let output = transpile_source(
+            "contract Proxy {
+                address implementation;
+
+                fallback() external payable {
+                    address target = implementation;
+                    assembly {
+                        calldatacopy(0, 0, calldatasize())
+                        let result := delegatecall(gas(), target, 0, calldatasize(), 0, 0)
+                        returndatacopy(0, 0, returndatasize())
+                    }
+                }
+            }",
+        );
+        let advice = output
+            .find("// Sol2Ink Not Implemented yet: delegatecall, ink! contracts delegate to the code hash")
+            .unwrap();
+        let assembly = output
+            .find("// Please handle assembly blocks manually >>>")
+            .unwrap();
+        assert!(advice < assembly);
+        assert!(output.contains("DelegateCall::new().code_hash(code_hash)"));
+        assert!(output
+            .contains("// let result := delegatecall(gas(), target, 0, calldatasize(), 0, 0)"));
+    }
+
+    #[test]
+    fn constructor_from_source() {
+        let output = transpile_source(
+            "contract Token {
+                address public owner;
+                uint256 public supply;
+                uint256 public cap = 100;
+                address public creator = msg.sender;
+
+                constructor(uint256 initialSupply, address admin) {
+                    owner = admin;
+                    supply = initialSupply;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "pub fn new(initial_supply: u128, admin: AccountId) -> Self {
+                ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                    instance.data.cap = 100;
+                    instance.data.creator = instance.env().caller();
+                    instance.data.owner = admin;
+                    instance.data.supply = initial_supply;
+                })
+            }"
+        ));
+    }
+
+    #[test]
+    fn mapping_array_length() {
+        let output = transpile_source(
+            "contract Holder {
+                mapping(address => uint256[]) userTokens;
+
+                function count(address u) public view returns (uint256) {
+                    return userTokens[u].length;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "user_tokens: Mapping<AccountId, Vec<u128>>"
+        ));
+        assert!(contains_code(
+            &output,
+            "return Ok((self.data.user_tokens.get(&u).unwrap_or_default().len() as u128))"
+        ));
+    }
+
+    #[test]
+    fn storage_pointer_write_back() {
+        let output = transpile_source(
+            "contract Market {
+                struct Order {
+                    uint256 amount;
+                    bool filled;
+                }
+
+                mapping(uint256 => Order) orders;
+
+                function take(uint256 id, uint256 amount) public returns (bool) {
+                    Order storage o = orders[id];
+                    if (o.filled) {
+                        return false;
+                    }
+                    o.amount -= amount;
+                    if (o.amount == 0) {
+                        o.filled = true;
+                    }
+                    return true;
+                }
+
+                function amountOf(uint256 id) public view returns (uint256) {
+                    Order storage o = orders[id];
+                    return o.amount;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "let mut o: Order = self.data.orders.get(&id).unwrap_or_default();
+            if o.filled {
+                self.data.orders.insert(&id, &(o));
+                return Ok(false)
+            }
+            o.amount -= amount;
+            if o.amount == 0 {
+                o.filled = true;
+            }
+            self.data.orders.insert(&id, &(o));
+            return Ok(true)"
+        ));
+        assert!(contains_code(
+            &output,
+            "let o: Order = self.data.orders.get(&id).unwrap_or_default();
+            return Ok(o.amount)"
+        ));
+    }
+
+    #[test]
+    fn base_constructor_initialization() {
+        let output = transpile_source(
+            "contract Token is ERC20, Ownable {
+                uint256 public cap;
+
+                constructor(address admin, uint256 cap_) ERC20(\"Token\", \"TKN\") Ownable(admin) {
+                    cap = cap_;
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "ink_lang::codegen::initialize_contract(|instance: &mut Self| {
+                instance._init_with_owner(admin);
+                // Sol2Ink Not Implemented yet: base constructor ERC20(\"Token\",\"TKN\")
+                instance.data.cap = cap;
+            })"
+        ));
+        assert!(!output.contains("instance._init_with_owner(instance.env().caller())"));
+    }
+
+    #[test]
+    fn computed_mapping_getter() {
+        let source = "contract Quota {
+            mapping(address => uint256) limit;
+            mapping(address => uint256) used;
+
+            function available(address u) public view returns (uint256) {
+                return limit[u] - used[u];
+            }
+        }";
+        let output = transpile_source(source);
+        assert!(contains_code(
+            &output,
+            "pub fn available(&self, u: AccountId) -> Result<u128, Error> {
+                return Ok(self.data.limit.get(&u).unwrap_or_default()
+                    - self.data.used.get(&u).unwrap_or_default())
+            }"
+        ));
+
+        let checked = transpile_source_with_config(
+            source,
+            &Config {
+                checked_arithmetic: true,
+                ..Default::default()
+            },
+        );
+        assert!(contains_code(
+            &checked,
+            "return Ok(self.data.limit.get(&u).unwrap_or_default()
+                .checked_sub(self.data.used.get(&u).unwrap_or_default())
+                .ok_or(Error::Custom(String::from(\"Underflow\")))?)"
+        ));
+    }
+
+    #[test]
+    fn overloaded_function_calls() {
+        let output = transpile_source(
+            "contract calc {
+                function add(uint256 a) public pure returns (uint256) {
+                    return a + 1;
+                }
+
+                function add(uint256 a, uint256 b) public pure returns (uint256) {
+                    return a + b;
+                }
+
+                function mul(uint256 a) public pure returns (uint256) {
+                    return a;
+                }
+
+                function mul(uint256 a, uint256 b) public pure returns (uint256) {
+                    return a * b;
+                }
+
+                function mul(uint256 a, address b) public pure returns (uint256) {
+                    return a;
+                }
+
+                function double(uint256 a) public pure returns (uint256) {
+                    return add(a, a);
+                }
+
+                function next(uint256 a) public pure returns (uint256) {
+                    return add(a);
+                }
+
+                function square(uint256 a) public pure returns (uint256) {
+                    return mul(a, a);
+                }
+            }",
+        );
+        assert!(output.contains(
+            "///Sol2Ink: renamed from the overloaded function `add`, which changes its selector"
+        ));
+        assert!(output.contains("return Ok(self.add_with_b(a, a)?)"));
+        assert!(output.contains("return Ok(self.add(a)?)"));
+        assert!(contains_code(
+            &output,
+            "todo!(\"Sol2Ink Not Implemented yet: mul(a, a) calls one of the overloads with 2 parameters\")"
+        ));
+    }
+
+    #[test]
+    fn missing_return_defaults() {
+        let output = transpile_source(
+            "contract F {
+                function f(uint a) public pure returns (uint) {
+                    if (a > 0) {
+                        return 1;
+                    }
+                }
+
+                function g(uint a) public pure returns (uint) {
+                    if (a > 0) {
+                        return 1;
+                    } else {
+                        return 2;
+                    }
+                }
+            }",
+        );
+        assert!(contains_code(
+            &output,
+            "if a > 0 {
+                return Ok(1)
+            }
+            Ok(Default::default())
+        }"
+        ));
+        assert_eq!(output.matches("Ok(Default::default())").count(), 1);
+    }
 }
@@ -21,13 +21,24 @@
 // SOFTWARE.
 
 use crate::{
+    config::Config,
     formatter::*,
     structures::*,
 };
+use convert_case::{
+    Case::{
+        Pascal,
+        UpperSnake,
+    },
+    Casing,
+};
 use lazy_static::lazy_static;
+use proc_macro2::Literal;
+use quote::quote;
 use regex::Regex;
 use std::{
     collections::{
+        BTreeSet,
         HashMap,
         HashSet,
         VecDeque,
@@ -37,12 +48,6 @@ use std::{
 };
 use substring::Substring;
 
-#[derive(Debug, PartialEq, Eq)]
-enum ArgsReader {
-    ArgType,
-    ArgName,
-}
-
 macro_rules! selector {
     ($constructor:ident) => {
         if $constructor {
@@ -59,7 +64,18 @@ macro_rules! bx {
     };
 }
 
-const DEFAULT_ERROR: &str = "SMART CONTRACT MAKE PANIC BEEP BEEP BEEP";
+/// Prefix of the comments which replace the statements Sol2Ink could not translate
+const NOT_IMPLEMENTED: &str = "Sol2Ink Not Implemented yet";
+/// Structs with more fields than this are considered expensive to store as mapping values
+const LARGE_STRUCT_FIELDS: usize = 8;
+/// Hint appended to the calls of `EnumerableSet` functions, which are not translated
+const ENUMERABLE_SET_ADVICE: &str =
+    "EnumerableSet is not supported, use the `Vec` of values together with a `Mapping` of their positions";
+/// Hint appended to the delegate calls of statements and assembly blocks, which are not translated
+const DELEGATE_CALL_ADVICE: &str = "ink! contracts delegate to the code hash of the implementation, \
+    store the code hash instead of its address and forward the calls in a message with `selector = _` \
+    by `build_call::<DefaultEnvironment>().call_type(DelegateCall::new().code_hash(code_hash))` \
+    with `CallFlags::default().set_forward_input(true).set_tail_call(true)`";
 
 lazy_static! {
     static ref TYPES: HashMap<&'static str, (&'static str, Option<&'static str>, Option<&'static str>)> = {
@@ -116,77 +132,11 @@ lazy_static! {
             ),
         );
         map.insert("byte", ("u8", None, None));
-        map.insert("int8", ("i8", None, None));
-        map.insert("int16", ("i16", None, None));
-        map.insert("int24", ("i32", None, None));
-        map.insert("int32", ("i32", None, None));
-        map.insert("int40", ("i64", None, None));
-        map.insert("int48", ("i64", None, None));
-        map.insert("int56", ("i64", None, None));
-        map.insert("int64", ("i64", None, None));
-        map.insert("int72", ("i128", None, None));
-        map.insert("int80", ("i128", None, None));
-        map.insert("int88", ("i128", None, None));
-        map.insert("int96", ("i128", None, None));
-        map.insert("int104", ("i128", None, None));
-        map.insert("int112", ("i128", None, None));
-        map.insert("int120", ("i128", None, None));
-        map.insert("int128", ("i128", None, None));
-        map.insert("int136", ("i128", None, None));
-        map.insert("int144", ("i128", None, None));
-        map.insert("int152", ("i128", None, None));
-        map.insert("int160", ("i128", None, None));
-        map.insert("int168", ("i128", None, None));
-        map.insert("int176", ("i128", None, None));
-        map.insert("int184", ("i128", None, None));
-        map.insert("int192", ("i128", None, None));
-        map.insert("int200", ("i128", None, None));
-        map.insert("int208", ("i128", None, None));
-        map.insert("int216", ("i128", None, None));
-        map.insert("int224", ("i128", None, None));
-        map.insert("int232", ("i128", None, None));
-        map.insert("int240", ("i128", None, None));
-        map.insert("int248", ("i128", None, None));
-        map.insert("int256", ("i128", None, None));
-        map.insert("int", ("i128", None, None));
         map.insert("mapping", ("Mapping", None, None));
         map.insert(
             "string",
             ("String", None, Some("ink_prelude::string::String")),
         );
-        map.insert("uint8", ("u8", None, None));
-        map.insert("uint16", ("u16", None, None));
-        map.insert("uint24", ("u32", None, None));
-        map.insert("uint32", ("u32", None, None));
-        map.insert("uint40", ("u64", None, None));
-        map.insert("uint48", ("u64", None, None));
-        map.insert("uint56", ("u64", None, None));
-        map.insert("uint64", ("u64", None, None));
-        map.insert("uint72", ("u128", None, None));
-        map.insert("uint80", ("u128", None, None));
-        map.insert("uint88", ("u128", None, None));
-        map.insert("uint96", ("u128", None, None));
-        map.insert("uint104", ("u128", None, None));
-        map.insert("uint112", ("u128", None, None));
-        map.insert("uint120", ("u128", None, None));
-        map.insert("uint128", ("u128", None, None));
-        map.insert("uint136", ("u128", None, None));
-        map.insert("uint144", ("u128", None, None));
-        map.insert("uint152", ("u128", None, None));
-        map.insert("uint160", ("u128", None, None));
-        map.insert("uint168", ("u128", None, None));
-        map.insert("uint176", ("u128", None, None));
-        map.insert("uint184", ("u128", None, None));
-        map.insert("uint192", ("u128", None, None));
-        map.insert("uint200", ("u128", None, None));
-        map.insert("uint208", ("u128", None, None));
-        map.insert("uint216", ("u128", None, None));
-        map.insert("uint224", ("u128", None, None));
-        map.insert("uint232", ("u128", None, None));
-        map.insert("uint240", ("u128", None, None));
-        map.insert("uint248", ("u128", None, None));
-        map.insert("uint256", ("u128", None, None));
-        map.insert("uint", ("u128", None, None));
         map
     };
     static ref OPERATIONS: HashMap<String, Operation> = {
@@ -227,7 +177,18 @@ lazy_static! {
         map.insert(String::from("address(0)"), Expression::ZeroAddressInto);
         map.insert(String::from("address(0x0)"), Expression::ZeroAddressInto);
         map.insert(String::from("msg.sender"), Expression::EnvCaller(None));
+        map.insert(String::from("address(this)"), Expression::EnvAccountId(None));
+        map.insert(String::from("address(this).balance"), Expression::EnvBalance(None));
+        map.insert(String::from("this.balance"), Expression::EnvBalance(None));
         map.insert(String::from("msg.value"), Expression::TransferredValue(None));
+        map.insert(
+            String::from("msg.data"),
+            Expression::NotImplemented(format!(
+                "{NOT_IMPLEMENTED}: msg.data, ink! does not expose the raw input of a message"
+            )),
+        );
+        map.insert(String::from("block.timestamp"), Expression::BlockTimestamp(None));
+        map.insert(String::from("block.number"), Expression::BlockNumber(None));
         map
     };
     static ref REGEX_RETURN: Regex =
@@ -245,6 +206,57 @@ lazy_static! {
         (,\s*["|'](?P<error>.*)["|']\s*)*\);\s*$"#
     )
     .unwrap();
+    static ref REGEX_REQUIRE_ARGS: Regex =
+        Regex::new(r#"(?x)^\s*require\s*\((?P<args>.+)\)\s*;*\s*$"#).unwrap();
+    static ref REGEX_ADDRESS_PAYABLE: Regex = Regex::new(r#"\baddress\s+payable\b"#).unwrap();
+    static ref REGEX_DATA_LOCATION: Regex = Regex::new(
+        r#"(?x)(?P<string>"(?:[^"\\]|\\.)*"|'(?:[^'\\]|\\.)*')|\s+(memory|storage|calldata)\b"#
+    )
+    .unwrap();
+    static ref REGEX_STORAGE_POINTER: Regex =
+        Regex::new(r#"(?x)^\s*[a-zA-Z0-9\[\]]+\s+storage\s+[_a-zA-Z0-9]+\s*="#).unwrap();
+    static ref REGEX_FIXED_ARRAY: Regex =
+        Regex::new(r#"(?x)^\s*(?P<element>.+)\[\s*(?P<size>[0-9]+)\s*\]\s*$"#).unwrap();
+    static ref REGEX_INT: Regex =
+        Regex::new(r#"(?x)^\s*(?P<int_type>u?int)(?P<int_size>[0-9]*)\s*$"#).unwrap();
+    static ref REGEX_STRING_LITERAL: Regex =
+        Regex::new(r#"(?x)^\s*["'](?P<value>.*)["']\s*$"#).unwrap();
+    static ref REGEX_STRING_CONCAT: Regex = Regex::new(
+        r#"(?x)
+        ^\s*(string\s*\(\s*abi\.encodePacked\s*\((?P<packed>.*)\)\s*\)
+        |string\.concat\s*\((?P<concat>.*)\))\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_TYPE_METADATA: Regex = Regex::new(
+        r#"(?x)
+        ^\s*type\s*\(\s*(?P<type>[a-zA-Z0-9_]+)\s*\)
+        \s*\.\s*(?P<member>[a-zA-Z0-9_]+)\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_EXTERNAL_CALL: Regex = Regex::new(
+        r#"(?x)
+        ^\s*I(?P<interface>[A-Z][a-zA-Z0-9_]*)\s*\(\s*(?P<address>[^()]+?)\s*\)
+        \s*\.\s*(?P<function_name>[a-zA-Z0-9_]+)\s*\((?P<args>.*)\);*\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_MEMBER_CALL: Regex = Regex::new(
+        r#"(?x)
+        ^\s*(?P<member>[a-zA-Z0-9_]+)
+        \s*\.\s*(?P<function_name>[a-zA-Z0-9_]+)\s*\((?P<args>.*)\);*\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_ENUMERABLE_SET_CALL: Regex = Regex::new(
+        r#"(?x)
+        ^\s*(?P<set>[a-zA-Z0-9_]+)\s*\.\s*(add|remove|contains|length|at|values)
+        \s*\((?P<args>.*)\);*\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_INTERFACE_TYPE: Regex = Regex::new(r#"^I[A-Z][a-zA-Z0-9_]*$"#).unwrap();
+    static ref REGEX_MAPPING_KEY: Regex = Regex::new(
+        r#"(?x)mapping\s*\(\s*(?P<key_type>[a-zA-Z0-9_]+)(\s+(?P<key_name>[a-zA-Z0-9_]+))?\s*=>"#
+    )
+    .unwrap();
+    static ref REGEX_PUBLIC: Regex = Regex::new(r#"(^|\s|\))public\s"#).unwrap();
     static ref REGEX_COMMENT: Regex = Regex::new(r#"(?x)^\s*///*\s*(?P<comment>.*)\s*$"#).unwrap();
     static ref REGEX_CONDITION_ONE_LINE: Regex = Regex::new(
         r#"(?x)
@@ -264,6 +276,14 @@ lazy_static! {
     static ref REGEX_TRY: Regex = Regex::new(r#"(?x)^\s*try\s*.*$"#).unwrap();
     static ref REGEX_ASSEMBLY: Regex = Regex::new(r#"(?x)^\s*assembly\s*\{\s*"#).unwrap();
     static ref REGEX_CATCH: Regex = Regex::new(r#"(?x)^\s*catch\s*.*$"#).unwrap();
+    static ref REGEX_DELETE: Regex =
+        Regex::new(r#"(?x)^\s*delete\s+(?P<target>.+?)\s*;*\s*$"#).unwrap();
+    static ref REGEX_REVERT_ERROR: Regex = Regex::new(
+        r#"(?x)^\s*revert\s+(?P<error>[a-zA-Z0-9_]+)\s*\((?P<args>.*)\)\s*;*\s*$"#
+    )
+    .unwrap();
+    static ref REGEX_REVERT: Regex =
+        Regex::new(r#"(?x)^\s*revert\s*\((?P<message>.*)\)\s*;*\s*$"#).unwrap();
     static ref REGEX_EMIT: Regex = Regex::new(
         r#"(?x)
         ^\s*emit\s+(?P<event_name>.+?)\s*\(\s*
@@ -287,7 +307,7 @@ lazy_static! {
     static ref REGEX_BOOLEAN: Regex = Regex::new(
         r#"(?x)
         ^\s*(?P<left>.+?)
-        \s*(?P<operation>[!=><^]+)\s*
+        \s*(?P<operation>[=><^]+|!=)\s*
         (?P<right>.+)
         \s*$"#,
     )
@@ -322,6 +342,24 @@ lazy_static! {
         \);*\s*\{*$"#,
     )
     .unwrap();
+    static ref REGEX_STRUCT_INITIALIZER: Regex =
+        Regex::new(r#"(?x)^\s*(?P<code>.+)\s*\(\{$"#).unwrap();
+    static ref REGEX_POSTFIX_INCREMENT: Regex = Regex::new(
+        r#"(?x)
+        (?P<value>[a-zA-Z_][a-zA-Z0-9_.]*(\[[^\]]+\])*)
+        \s*(?P<operation>\+\+|--)"#,
+    )
+    .unwrap();
+    static ref REGEX_PREFIX_INCREMENT: Regex = Regex::new(
+        r#"(?x)
+        (?P<operation>\+\+|--)\s*
+        (?P<value>[a-zA-Z_][a-zA-Z0-9_.]*(\[[^\]]+\])*)"#,
+    )
+    .unwrap();
+    static ref REGEX_ENCODE_PACKED: Regex =
+        Regex::new(r#"(?x)^\s*abi\.encodePacked\(\s*(?P<value>[^,]+?)\s*\)\s*$"#).unwrap();
+    static ref REGEX_BYTES_CAST: Regex =
+        Regex::new(r#"(?x)^\s*bytes\(\s*(?P<value>.+?)\s*\)\s*$"#).unwrap();
     static ref REGEX_TERNARY:Regex = Regex::new(
         r#"(?x)
         ^\s*(?P<condition>.+?)\s*\?
@@ -336,6 +374,7 @@ pub enum ParserError {
     FileError(String),
     FileCorrupted,
     LibraryParsingNotImplemented,
+    MultipleContracts(Vec<String>),
 }
 
 impl From<std::io::Error> for ParserError {
@@ -383,10 +422,33 @@ pub struct Parser<'a> {
     events: &'a mut HashMap<String, Event>,
     modifiers: &'a mut HashMap<String, ()>,
     structs: &'a mut HashMap<String, Struct>,
+    config: &'a Config,
+    locals: HashMap<String, String>,
+    /// the Solidity types of the parameters and local variables of the currently parsed function
+    solidity_types: HashMap<String, String>,
+    return_types: Vec<String>,
+    helpers: BTreeSet<Helper>,
+    bases: Vec<OpenBrushBase>,
+    enums: HashSet<String>,
+    errors: HashSet<String>,
+    enumerable_sets: HashSet<String>,
+    storage_pointers: HashSet<String>,
+    overloads: HashMap<(String, usize), Option<String>>,
+    interface_fields: HashMap<String, String>,
+    contracts: HashMap<String, Contract>,
+    /// the number of temporaries holding the value of a post-incremented variable
+    /// in the currently parsed function by the name of the variable
+    temporaries: HashMap<String, usize>,
+    /// the libraries attached to all types by `using Lib for *`
+    global_libraries: Vec<String>,
+    source: &'a str,
+    location: Option<Location>,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl<'a> Parser<'a> {
     /// creates a new parser from the given parameters
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         chars: &'a mut Chars<'a>,
         imports: &'a mut HashSet<String>,
@@ -395,7 +457,9 @@ impl<'a> Parser<'a> {
         events: &'a mut HashMap<String, Event>,
         modifiers: &'a mut HashMap<String, ()>,
         structs: &'a mut HashMap<String, Struct>,
+        config: &'a Config,
     ) -> Self {
+        let source = chars.as_str();
         Parser {
             chars,
             imports,
@@ -404,18 +468,87 @@ impl<'a> Parser<'a> {
             events,
             modifiers,
             structs,
+            config,
+            locals: HashMap::new(),
+            solidity_types: HashMap::new(),
+            return_types: Vec::new(),
+            helpers: BTreeSet::new(),
+            bases: Vec::new(),
+            enums: HashSet::new(),
+            errors: HashSet::new(),
+            enumerable_sets: HashSet::new(),
+            storage_pointers: HashSet::new(),
+            overloads: HashMap::new(),
+            interface_fields: HashMap::new(),
+            contracts: HashMap::new(),
+            temporaries: HashMap::new(),
+            global_libraries: Vec::new(),
+            source,
+            location: None,
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// returns the constructs which could not be translated, in the order they were parsed
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    /// returns the location of the last character read from the source code
+    fn current_location(&self) -> Location {
+        let offset = (self.source.len() - self.chars.as_str().len()).saturating_sub(1);
+        let line_start = self.source[..offset]
+            .rfind(NEW_LINE)
+            .map_or(0, |index| index + 1);
+        Location {
+            line: self.source[..offset].matches(NEW_LINE).count() + 1,
+            column: self.source[line_start..=offset].chars().count(),
+        }
+    }
+
+    /// Records a construct which could not be translated at the location of the parsed statement
+    ///
+    /// `message` the message of the generated TODO
+    ///
+    /// returns the message
+    fn not_implemented(&mut self, message: String) -> String {
+        let location = self.location.unwrap_or_else(|| self.current_location());
+        self.diagnostics.push(Diagnostic {
+            location,
+            message: message.clone(),
+        });
+        message
+    }
+
+    /// Records a statement which could not be translated and leaves it as a comment
+    ///
+    /// `message` the content of the comment
+    ///
+    /// returns the comment, ending with the location of the statement if `inline_locations` is set
+    fn not_implemented_comment(&mut self, message: String) -> Statement {
+        let message = self.not_implemented(message);
+        match self.location {
+            Some(location) if self.config.inline_locations => {
+                Statement::Comment(format!("{message} // {location}"))
+            }
+            _ => Statement::Comment(message),
         }
     }
 
     /// parses the file represented by the given chars iterator
     ///
-    /// returns Some(contract) if a contract was successfully parsed
+    /// contracts declared earlier in the file are merged into the contracts inheriting them
+    ///
+    /// returns Some(contract) with the contract which is not inherited by another contract of the file
+    /// returns an error if there are several such contracts
     /// returns Some(interface) if an interface was successfully parsed
     /// returns None if the file is not a valid contract or interface
     pub fn parse_file(&mut self) -> Result<(Option<Contract>, Option<Interface>), ParserError> {
         let mut comments = Vec::<String>::new();
         let mut action = Action::None;
         let mut buffer = String::new();
+        let mut abstract_contract = false;
+        let mut contract_names = Vec::<String>::new();
 
         while let Some(ch) = self.chars.next() {
             match ch {
@@ -432,17 +565,25 @@ impl<'a> Parser<'a> {
                     comments.append(&mut new_comments);
                     action = Action::None;
                 }
-                NEW_LINE | SPACE => {}
+                _ if ch.is_whitespace() => {}
                 _ => {
                     buffer.push(ch);
                     if buffer == "pragma" || buffer == "import" {
                         read_until(self.chars, vec![SEMICOLON]);
                         buffer.clear();
                     } else if buffer == "abstract" {
+                        abstract_contract = true;
                         buffer.clear();
                     } else if buffer == "contract" {
-                        let contract = self.parse_contract(comments)?;
-                        return Ok((Some(contract), None))
+                        let contract =
+                            self.parse_contract(std::mem::take(&mut comments), abstract_contract)?;
+                        contract_names.push(contract.name.clone());
+                        self.contracts.insert(contract.name.clone(), contract);
+                        abstract_contract = false;
+                        buffer.clear();
+                    } else if buffer == "interface" && !contract_names.is_empty() {
+                        self.parse_interface(std::mem::take(&mut comments))?;
+                        buffer.clear();
                     } else if buffer == "interface" {
                         let interface = self.parse_interface(comments)?;
                         return Ok((None, Some(interface)))
@@ -453,7 +594,23 @@ impl<'a> Parser<'a> {
             }
         }
 
-        Ok((None, None))
+        let mut emitted = contract_names
+            .into_iter()
+            .filter(|name| {
+                !self
+                    .contracts
+                    .values()
+                    .any(|contract| contract.base_contracts.contains(name))
+            })
+            .collect::<Vec<_>>();
+        if emitted.len() > 1 {
+            return Err(ParserError::MultipleContracts(emitted))
+        }
+
+        Ok((
+            emitted.pop().and_then(|name| self.contracts.remove(&name)),
+            None,
+        ))
     }
 
     /// parses a line containing a comment and returns it as a string
@@ -536,9 +693,14 @@ impl<'a> Parser<'a> {
     /// Parses the code of a Solidity contract
     ///
     /// `contract_doc` the documentation comments of the contract
+    /// `abstract_contract` if the contract is abstract, its constructor is parsed as an `_init` function
     ///
     /// returns the representation of the contract as `Contract` struct
-    fn parse_contract(&mut self, contract_doc: Vec<String>) -> Result<Contract, ParserError> {
+    fn parse_contract(
+        &mut self,
+        contract_doc: Vec<String>,
+        abstract_contract: bool,
+    ) -> Result<Contract, ParserError> {
         let mut buffer = String::new();
         let mut action = Action::None;
 
@@ -546,11 +708,20 @@ impl<'a> Parser<'a> {
         let mut comments = Vec::<String>::new();
         let mut fields = Vec::<ContractField>::new();
         let mut events = Vec::<Event>::new();
+        let mut errors = Vec::<CustomError>::new();
         let mut enums = Vec::<Enum>::new();
         let mut structs = Vec::<Struct>::new();
         let mut functions = Vec::<Function>::new();
         let mut constructor = Function::default();
+        let mut has_constructor = false;
+        let mut constructor_location = None;
         let mut modifiers = Vec::<Modifier>::new();
+        let mut base_contracts = Vec::<String>::new();
+        let mut getters = Vec::<usize>::new();
+        // the imports of the contracts parsed before are added only to the contracts inheriting them
+        self.imports.clear();
+        // `using` directives are not inherited
+        self.global_libraries.clear();
 
         while let Some(ch) = self.chars.next() {
             match ch {
@@ -571,10 +742,9 @@ impl<'a> Parser<'a> {
                 SPACE | CURLY_OPEN if action == Action::ContractName => {
                     name = buffer.trim().to_string();
                     buffer.clear();
-                    // we skip everything regarding generalization
-                    // TODO: cover generaliztaion
                     if ch != CURLY_OPEN {
-                        read_until(self.chars, vec![CURLY_OPEN]);
+                        let inheritance = read_until(self.chars, vec![CURLY_OPEN]);
+                        base_contracts = parse_base_contracts(&inheritance);
                     }
                     action = Action::Contract;
                 }
@@ -582,9 +752,16 @@ impl<'a> Parser<'a> {
                     buffer.push(ch);
                     action = Action::ContractName;
                 }
+                // the members consume their own bodies, so this closes the contract
+                CURLY_CLOSE if action == Action::Contract && buffer.trim().is_empty() => break,
                 SEMICOLON if action == Action::Contract => {
                     buffer.push(ch);
-                    fields.push(self.parse_contract_field(buffer.trim(), &comments));
+                    let field = self.parse_contract_field(buffer.trim(), &comments);
+                    if let Some(getter) = self.create_getter(buffer.trim(), &field) {
+                        getters.push(functions.len());
+                        functions.push(getter);
+                    }
+                    fields.push(field);
                     buffer.clear();
                     comments.clear();
                 }
@@ -598,6 +775,13 @@ impl<'a> Parser<'a> {
                             comments.clear();
                             buffer.clear();
                         }
+                        "error" => {
+                            let error = self.parse_custom_error(&comments);
+                            self.errors.insert(error.name.clone());
+                            errors.push(error);
+                            comments.clear();
+                            buffer.clear();
+                        }
                         "enum" => {
                             enums.push(self.parse_enum(&comments));
                             comments.clear();
@@ -614,7 +798,9 @@ impl<'a> Parser<'a> {
                             buffer.clear();
                         }
                         "constructor" => {
+                            constructor_location = Some(self.current_location());
                             constructor = self.parse_function(&comments)?;
+                            has_constructor = true;
                             comments.clear();
                             buffer.clear();
                         }
@@ -624,7 +810,13 @@ impl<'a> Parser<'a> {
                             comments.clear();
                         }
                         "using" => {
-                            read_until(self.chars, vec![SEMICOLON]);
+                            let directive = read_until(self.chars, vec![SEMICOLON]);
+                            if let Some((library, "*")) = directive
+                                .split_once(" for ")
+                                .map(|(library, target)| (library, target.trim()))
+                            {
+                                self.global_libraries.push(library.trim().to_owned());
+                            }
                             buffer.clear();
                         }
                         "receive" | "fallback" => {
@@ -641,6 +833,51 @@ impl<'a> Parser<'a> {
             }
         }
 
+        // explicitly declared functions take precedence over the generated getters
+        let explicit = functions
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !getters.contains(index))
+            .map(|(_, function)| function.header.name.clone())
+            .collect::<HashSet<_>>();
+        let mut index = 0;
+        functions.retain(|function| {
+            let clashing = getters.contains(&index) && explicit.contains(&function.header.name);
+            index += 1;
+            !clashing
+        });
+
+        // the constructor of an abstract contract is only called by the inheriting contracts
+        if abstract_contract && has_constructor {
+            let mut init = std::mem::take(&mut constructor);
+            init.header.name = String::from("init");
+            init.header.external = false;
+            init.header.view = false;
+            functions.push(init);
+        }
+
+        // the bases of the inherited contracts declared in this file are inherited as well
+        for base in base_contracts.clone().iter() {
+            if let Some(contract) = self.contracts.get(base) {
+                for inherited in contract.base_contracts.iter() {
+                    if !base_contracts.contains(inherited) {
+                        base_contracts.push(inherited.clone());
+                    }
+                }
+            }
+        }
+        self.bases = base_contracts
+            .iter()
+            .filter_map(|base| OpenBrushBase::from_name(base))
+            .collect();
+        for base in self.bases.clone().iter() {
+            self.add_base(*base, &mut fields);
+        }
+
+        self.overloads =
+            rename_overloads(functions.iter_mut().map(|function| &mut function.header));
+        remove_struct_mappings(&mut structs);
+        add_storage_advice(&mut fields, &structs);
         for contract_field in fields.iter() {
             self.storage
                 .insert(contract_field.name.clone(), contract_field.clone());
@@ -660,25 +897,91 @@ impl<'a> Parser<'a> {
         // now we know the contracts members and we can parse statements
         for function in functions.iter_mut() {
             function.header.modifiers = self.process_function_modifiers(&function.header.modifiers);
+            self.set_locals(&function.header);
             function.body = self.parse_statements(&function.body, false);
+            function.untranslated = has_untranslated(&mut function.body);
         }
         for modifier in modifiers.iter_mut() {
+            self.set_locals(&modifier.header);
             modifier.statements = self.parse_statements(&modifier.statements, false);
         }
+        self.set_locals(&constructor.header);
         constructor.body = self.parse_statements(&constructor.body, true);
+        // the base constructors are called in the header of the constructor
+        self.location = constructor_location;
+        let base_initialization = self.parse_base_initialization(&constructor.header.modifiers);
+        self.location = None;
+        constructor.body.splice(0..0, base_initialization);
+
+        if self.config.extract_requires {
+            extract_repeated_requires(&mut functions);
+        }
+
+        // the `Error` enum of the contract always contains `Custom(String)`
+        self.imports
+            .insert(String::from("use ink_prelude::string::String;"));
 
-        Ok(Contract {
+        let mut contract = Contract {
             name,
             fields,
             constructor,
             events,
+            errors,
             enums,
             structs,
             functions,
             imports: self.imports.clone(),
-            contract_doc,
+            contract_doc: format_natspec(&contract_doc),
             modifiers,
-        })
+            helpers: self.helpers.clone(),
+            base_contracts,
+        };
+        for base in contract.base_contracts.clone().iter() {
+            if let Some(base_contract) = self.contracts.get(base) {
+                merge_base_contract(&mut contract, base_contract);
+            }
+        }
+
+        Ok(contract)
+    }
+
+    /// Adds the imports and constants of a base contract implemented by OpenBrush
+    ///
+    /// `base` the base contract
+    /// `fields` the fields of the contract
+    fn add_base(&mut self, base: OpenBrushBase, fields: &mut [ContractField]) {
+        self.imports
+            .insert(format!("use openbrush::contracts::{}::*;", base.module()));
+        for constant in base.constants().iter() {
+            self.storage.insert(
+                constant.to_string(),
+                ContractField {
+                    field_type: String::new(),
+                    name: constant.to_string(),
+                    comments: Vec::default(),
+                    initial_value: None,
+                    constant: true,
+                },
+            );
+        }
+
+        if base == OpenBrushBase::AccessControl {
+            // roles are identified by `RoleType` instead of the hash of their name
+            for field in fields.iter_mut().filter(|field| field.constant) {
+                if let Some(Expression::FunctionCall(function_name, args, _, _)) =
+                    &field.initial_value
+                {
+                    if let (true, [Expression::Literal(role)]) =
+                        (function_name == "keccak256", args.as_slice())
+                    {
+                        field.initial_value = Some(Expression::Literal(format!(
+                            "ink_lang::selector_id!({role})"
+                        )));
+                        field.field_type = String::from("RoleType");
+                    }
+                }
+            }
+        }
     }
 
     /// Parses the code of a Solidity interface
@@ -753,6 +1056,8 @@ impl<'a> Parser<'a> {
             }
         }
 
+        rename_overloads(function_headers.iter_mut());
+
         Ok(Interface {
             name,
             events,
@@ -760,7 +1065,7 @@ impl<'a> Parser<'a> {
             structs,
             function_headers,
             imports: self.imports.clone(),
-            comments: contract_comments,
+            comments: format_natspec(&contract_comments),
         })
     }
 
@@ -771,7 +1076,7 @@ impl<'a> Parser<'a> {
     ///
     /// returns the representation of contract field as `ContractField` struct
     fn parse_contract_field(&mut self, line_raw: &str, comments: &[String]) -> ContractField {
-        let mut line = line_raw.to_owned();
+        let mut line = remove_address_payable(line_raw);
         line = Regex::new(r"\s*=>\s*")
             .unwrap()
             .replace_all(&line, "=>")
@@ -784,6 +1089,14 @@ impl<'a> Parser<'a> {
             .unwrap()
             .replace_all(&line, ")")
             .to_string();
+        // removes the names of keys and values of mappings
+        line = REGEX_MAPPING_KEY
+            .replace_all(&line, "mapping(${key_type}=>")
+            .to_string();
+        line = Regex::new(r"=>(?P<value_type>[a-zA-Z0-9_]+)\s+[a-zA-Z0-9_]+\)")
+            .unwrap()
+            .replace_all(&line, "=>${value_type})")
+            .to_string();
 
         let regex: Regex = Regex::new(
             r#"(?x)^\s*
@@ -799,67 +1112,212 @@ impl<'a> Parser<'a> {
         let attributes_raw = capture_regex(&regex, &line, "attributes");
         let field_name = capture_regex(&regex, &line, "field_name").unwrap();
         let initial_value_maybe = capture_regex(&regex, &line, "initial_value");
-        let initial_value =
-            initial_value_maybe.map(|initial_raw| self.parse_expression(&initial_raw, false, None));
         let constant = attributes_raw
             .unwrap_or_else(|| String::from(""))
             .contains("constant");
-        let field_type = self.convert_variable_type(trim(&field_type_raw));
+        // non-constant initial values are assigned inside of the constructor
+        let mut initial_value = initial_value_maybe
+            .clone()
+            .map(|initial_raw| self.parse_expression(&initial_raw, !constant, None));
+        let mut field_type = self.convert_variable_type(trim(&field_type_raw));
+
+        // `String` and `Vec` can not be created in a constant, so we use slices instead
+        if constant && field_type == "String" {
+            field_type = String::from("&str");
+        } else if constant && field_type == "Vec<u8>" {
+            field_type = String::from("&[u8]");
+            initial_value = initial_value_maybe
+                .and_then(|initial_raw| byte_slice(&initial_raw))
+                .map(Expression::Literal)
+                .or(initial_value);
+        }
+
+        // the contracts stored by their address are called through the interface of the field
+        if let Some(interface) = field_type_raw
+            .trim()
+            .strip_prefix('I')
+            .filter(|_| REGEX_INTERFACE_TYPE.is_match(field_type_raw.trim()))
+        {
+            self.interface_fields
+                .insert(field_name.clone(), interface.to_owned());
+        }
+
+        let mut comments = comments.to_vec();
+        if field_type_raw.trim().starts_with("EnumerableSet.") {
+            self.enumerable_sets.insert(field_name.clone());
+            comments.push(String::from(
+                "Sol2Ink: EnumerableSet is not supported by ink!, the values are kept in a `Vec`, consider adding a `Mapping` from the value to its position in the `Vec`",
+            ));
+        }
 
         ContractField {
             field_type,
             name: field_name,
-            comments: comments.to_vec(),
+            comments,
             initial_value,
             constant,
         }
     }
 
+    /// Creates the getter function which solidity generates for a public storage field
+    ///
+    /// `line` the solidity declaration of the field
+    /// `field` the parsed field
+    ///
+    /// returns the getter as `Function` struct or `None` if the field is not public
+    fn create_getter(&mut self, line: &str, field: &ContractField) -> Option<Function> {
+        if field.constant || !REGEX_PUBLIC.is_match(line) {
+            return None
+        }
+
+        // keys of mappings are named `key0`, `key1`... if the declaration does not name them
+        let key_names = REGEX_MAPPING_KEY
+            .captures_iter(line)
+            .enumerate()
+            .map(|(index, captures)| {
+                captures
+                    .name("key_name")
+                    .map(|name| name.as_str().to_owned())
+                    .unwrap_or_else(|| format!("key{index}"))
+            })
+            .collect::<Vec<_>>();
+        let (params, return_type) = match split_mapping_type(&field.field_type) {
+            Some((key_type, value_type)) => {
+                let key_types = match key_type.strip_prefix('(') {
+                    Some(tuple) => split_args(tuple.strip_suffix(')').unwrap_or(tuple)),
+                    None => vec![key_type.to_owned()],
+                };
+                let params = key_names
+                    .into_iter()
+                    .zip(key_types)
+                    .map(|(name, param_type)| {
+                        FunctionParam {
+                            name,
+                            param_type,
+                            ..Default::default()
+                        }
+                    })
+                    .collect();
+                (params, value_type.to_owned())
+            }
+            None => {
+                match field
+                    .field_type
+                    .strip_prefix("Vec<")
+                    .and_then(|element| element.strip_suffix('>'))
+                {
+                    Some(element_type) => {
+                        (
+                            vec![FunctionParam {
+                                name: String::from("index"),
+                                param_type: String::from("u128"),
+                                ..Default::default()
+                            }],
+                            element_type.to_owned(),
+                        )
+                    }
+                    None => (Vec::default(), field.field_type.clone()),
+                }
+            }
+        };
+        let indices = params
+            .iter()
+            .map(|param| format!("[{}]", param.name))
+            .collect::<String>();
+
+        Some(Function {
+            header: FunctionHeader {
+                name: field.name.clone(),
+                params,
+                external: true,
+                view: true,
+                return_params: vec![FunctionParam {
+                    name: String::from("_"),
+                    param_type: return_type,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+            body: vec![Statement::Raw(
+                format!("return {}{indices};", field.name),
+                self.current_location(),
+            )],
+            ..Default::default()
+        })
+    }
+
     /// Parses Solidity event
     ///
     /// `comments` the documentation comments of the event
     ///
     /// returns the event definition as `Event` struct
     fn parse_event(&mut self, comments: &[String]) -> Event {
-        let event_raw = read_until(self.chars, vec![SEMICOLON])
-            .trim()
-            .replace("( ", "(")
-            .replace(" )", ")");
-
-        let tokens = split(&event_raw, " ", None);
-        let mut args_reader = ArgsReader::ArgName;
-        let mut indexed = false;
-
-        let split_brace = split(&tokens[0], "(", None);
+        let event_raw = trim(&read_until(self.chars, vec![SEMICOLON]));
+        let args_start = event_raw.find('(').unwrap_or(event_raw.len());
+        let args_end = event_raw.rfind(')').unwrap_or(event_raw.len());
 
-        let name = split_brace[0].to_owned();
-        let mut field_type = self.convert_variable_type(split_brace[1].to_owned());
+        let name = event_raw[..args_start].trim().to_owned();
         let mut fields = Vec::<EventField>::new();
 
-        for item in tokens.iter().skip(1) {
-            let mut token = item.clone();
-            if token == "indexed" {
-                indexed = true;
-                continue
-            } else if args_reader == ArgsReader::ArgType {
-                field_type = self.convert_variable_type(token);
-                args_reader = ArgsReader::ArgName;
-            } else {
-                token.remove_matches(&[',', ')'][..]);
+        if args_start < args_end {
+            for (index, field_raw) in split(&event_raw[args_start + 1..args_end], ",", None)
+                .iter()
+                .enumerate()
+            {
+                let tokens = split(&trim(field_raw), " ", None);
+                if tokens[0].is_empty() {
+                    continue
+                }
+                let indexed = tokens.iter().any(|token| token == "indexed");
+                let field_type = self.convert_variable_type(tokens[0].to_owned());
+                // fields of events may be declared without a name
+                let name = tokens
+                    .iter()
+                    .skip(1)
+                    .rfind(|token| *token != "indexed" && *token != "payable")
+                    .cloned()
+                    .unwrap_or_else(|| format!("arg_{index}"));
                 fields.push(EventField {
                     indexed,
-                    field_type: field_type.to_owned(),
-                    name: token.to_owned(),
+                    field_type,
+                    name,
                 });
-                indexed = false;
-                args_reader = ArgsReader::ArgType;
             }
         }
 
         Event {
             name,
             fields,
-            comments: comments.to_vec(),
+            comments: format_natspec(comments),
+        }
+    }
+
+    /// Parses Solidity custom error
+    ///
+    /// `comments` the documentation comments of the error
+    ///
+    /// returns the error definition as `CustomError` struct
+    fn parse_custom_error(&mut self, comments: &[String]) -> CustomError {
+        let error_raw = trim(&read_until(self.chars, vec![SEMICOLON]));
+        let args_start = error_raw.find('(').unwrap_or(error_raw.len());
+        let args_end = error_raw.rfind(')').unwrap_or(error_raw.len());
+
+        let name = error_raw[..args_start].trim().to_owned();
+        let mut fields = Vec::<String>::new();
+
+        if args_start < args_end {
+            for field_raw in split_args(&error_raw[args_start + 1..args_end]) {
+                let tokens = split(&field_raw, " ", None);
+                if !tokens[0].is_empty() {
+                    fields.push(self.convert_variable_type(tokens[0].to_owned()));
+                }
+            }
+        }
+
+        CustomError {
+            name,
+            fields,
+            comments: format_natspec(comments),
         }
     }
 
@@ -870,23 +1328,42 @@ impl<'a> Parser<'a> {
     /// returns the enum as `Enum` struct
     fn parse_enum(&mut self, comments: &[String]) -> Enum {
         let enum_raw = read_until(self.chars, vec![CURLY_CLOSE]);
-        let tokens = split(&enum_raw, " ", None);
-        let name = tokens[0].to_owned();
-        let mut values = Vec::<String>::new();
+        let (name_raw, values_raw) = enum_raw.split_once(CURLY_OPEN).unwrap_or((&enum_raw, ""));
+        let name = name_raw.trim().to_owned();
+        // the last variant may be followed by a comma
+        let values = values_raw
+            .split(COMMA)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .map(|value| {
+                // the variant may assign an explicit discriminant
+                let (name, discriminant) = match value.split_once('=') {
+                    Some((name, discriminant)) => (name, Some(discriminant.trim().to_owned())),
+                    None => (value, None),
+                };
+                EnumValue {
+                    name: self.enum_variant(name.trim()),
+                    discriminant,
+                }
+            })
+            .collect();
 
-        for item in tokens.iter().skip(1) {
-            let mut token = item.clone();
-            if token == "{" {
-                continue
-            } else {
-                token.remove_matches(",");
-                values.push(token);
-            }
-        }
+        self.enums.insert(name.clone());
         Enum {
             name,
             values,
-            comments: comments.to_vec(),
+            comments: format_natspec(comments),
+        }
+    }
+
+    /// Returns the name of the enum variant, converted to PascalCase if set in the config
+    ///
+    /// `variant` the solidity name of the variant
+    fn enum_variant(&self, variant: &str) -> String {
+        if self.config.normalize_enum_variants {
+            variant.to_case(Pascal)
+        } else {
+            variant.to_owned()
         }
     }
 
@@ -896,11 +1373,11 @@ impl<'a> Parser<'a> {
     ///
     /// returns the struct definition as `Struct` struct
     fn parse_struct(&mut self, comments: &[String]) -> Struct {
-        let mut struct_raw = read_until(self.chars, vec![CURLY_CLOSE]);
+        let mut struct_raw = remove_address_payable(&read_until(self.chars, vec![CURLY_CLOSE]));
         struct_raw = struct_raw.replace(" => ", "=>");
         let split_brace = split(&struct_raw, "{", None);
         let fields = split(split_brace[1].trim(), ";", None);
-        let struct_name = split_brace[0].to_owned();
+        let struct_name = split_brace[0].trim().to_owned();
 
         let mut struct_fields = Vec::<StructField>::new();
 
@@ -914,7 +1391,7 @@ impl<'a> Parser<'a> {
         Struct {
             name: struct_name,
             fields: struct_fields,
-            comments: comments.to_vec(),
+            comments: format_natspec(comments),
         }
     }
 
@@ -937,9 +1414,31 @@ impl<'a> Parser<'a> {
     ///
     /// returns the function definition as `Function` struct
     fn parse_function(&mut self, comments: &[String]) -> Result<Function, ParserError> {
+        let abstract_function = self
+            .chars
+            .clone()
+            .find(|ch| *ch == SEMICOLON || *ch == CURLY_OPEN)
+            == Some(SEMICOLON);
+        let header = self.parse_function_header(comments);
+
+        // functions without body are implemented by the inheriting contracts,
+        // which keep the modifiers of the declaration
+        if abstract_function {
+            return Ok(Function {
+                header,
+                body: vec![Statement::FunctionCall(Expression::NotImplemented(
+                    String::from(
+                        "Sol2Ink: abstract function, implement it in the inheriting contract",
+                    ),
+                ))],
+                ..Default::default()
+            })
+        }
+
         Ok(Function {
-            header: self.parse_function_header(comments),
+            header,
             body: self.parse_body(),
+            ..Default::default()
         })
     }
 
@@ -949,10 +1448,10 @@ impl<'a> Parser<'a> {
     ///
     /// returns the representation of the function header as `FunctionHeader` struct
     fn parse_function_header(&mut self, comments: &[String]) -> FunctionHeader {
-        let mut function_header_raw = read_until(self.chars, vec![SEMICOLON, CURLY_OPEN]);
-        function_header_raw.remove_matches(" memory");
-        function_header_raw.remove_matches(" storage");
-        function_header_raw.remove_matches(" calldata");
+        let function_header_raw = remove_data_locations(&remove_address_payable(&read_until(
+            self.chars,
+            vec![SEMICOLON, CURLY_OPEN],
+        )));
 
         let regex_return_function = Regex::new(
             r#"(?x)
@@ -968,6 +1467,18 @@ impl<'a> Parser<'a> {
             "return_parameters",
         );
 
+        // contracts are referenced by their address in ink!
+        let mut comments = format_natspec(comments);
+        for interface in return_parameters_maybe
+            .iter()
+            .flat_map(|raw| raw.split(|ch: char| ch == COMMA || ch.is_whitespace()))
+            .filter(|token| REGEX_INTERFACE_TYPE.is_match(token))
+        {
+            comments.push(format!(
+                "Sol2Ink: the `{interface}` contract is returned as its `AccountId`"
+            ));
+        }
+
         let (name, params, return_params, modifiers) = if let Some(return_parameters_raw) =
             return_parameters_maybe
         {
@@ -980,7 +1491,16 @@ impl<'a> Parser<'a> {
             let parameters_raw =
                 capture_regex(&regex_return_function, &function_header_raw, "parameters").unwrap();
             let parameters = self.parse_function_parameters(parameters_raw);
-            let return_parameters = self.parse_return_parameters(return_parameters_raw);
+            let mut return_parameters = self.parse_return_parameters(return_parameters_raw);
+            // the named return variable would shadow the parameter
+            for return_parameter in return_parameters.iter_mut() {
+                if parameters
+                    .iter()
+                    .any(|parameter| parameter.name == return_parameter.name)
+                {
+                    return_parameter.name = format!("{}Out", return_parameter.name);
+                }
+            }
             let attribs_raw =
                 capture_regex(&regex_return_function, &function_header_raw, "attributes").unwrap();
             (
@@ -1021,7 +1541,7 @@ impl<'a> Parser<'a> {
             view,
             payable,
             return_params,
-            comments: comments.to_vec(),
+            comments,
             modifiers,
         }
     }
@@ -1035,23 +1555,21 @@ impl<'a> Parser<'a> {
         let mut out = Vec::<FunctionParam>::new();
 
         if !parameters.is_empty() {
-            let tokens = split(&parameters, " ", Some(remove_commas()));
-
-            let mut mode = ArgsReader::ArgName;
-            let mut param_type = self.convert_variable_type(tokens[0].to_owned());
-
-            for item in tokens.iter().skip(1) {
-                if mode == ArgsReader::ArgType {
-                    param_type = self.convert_variable_type(item.to_owned());
-                    mode = ArgsReader::ArgName;
-                } else if mode == ArgsReader::ArgName {
-                    let name = item.to_owned();
-                    out.push(FunctionParam {
-                        name,
-                        param_type: param_type.to_owned(),
-                    });
-                    mode = ArgsReader::ArgType;
-                }
+            for (index, parameter) in split(&parameters, ",", None).iter().enumerate() {
+                let tokens = split(&trim(parameter), " ", None);
+                let solidity_type = tokens[0].to_owned();
+                let param_type = self.convert_variable_type(solidity_type.clone());
+                // parameters of interface functions may be declared without a name
+                let name = if tokens.len() > 1 {
+                    tokens.last().unwrap().to_owned()
+                } else {
+                    format!("arg_{index}")
+                };
+                out.push(FunctionParam {
+                    name,
+                    param_type,
+                    solidity_type,
+                });
             }
         }
 
@@ -1071,14 +1589,18 @@ impl<'a> Parser<'a> {
         while let Some(token) = iterator.next() {
             let mut param_raw = token.to_owned();
             param_raw.remove_matches(",");
-            let param_type = self.convert_variable_type(param_raw);
+            let param_type = self.convert_variable_type(param_raw.clone());
             let mut name = if tokens.len() >= (parameters.matches(',').count() + 1) * 2 {
                 iterator.next().unwrap().to_owned()
             } else {
                 String::from("_")
             };
             name.remove_matches(",");
-            out.push(FunctionParam { name, param_type })
+            out.push(FunctionParam {
+                name,
+                param_type,
+                solidity_type: param_raw,
+            })
         }
 
         out
@@ -1091,8 +1613,31 @@ impl<'a> Parser<'a> {
         let mut close_braces = 0;
         let mut statements = Vec::<Statement>::new();
         let mut action = Action::None;
+        let mut location = Location::default();
+        // the quote of the string literal which is being read
+        let mut quote = None;
+        let mut escaped = false;
 
         while let Some(ch) = self.chars.next() {
+            if !ch.is_whitespace() && buffer.trim().is_empty() {
+                location = self.current_location();
+            }
+            // braces, semicolons and slashes of string literals do not delimit the statements
+            if let Some(open) = quote {
+                buffer.push(ch);
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == open {
+                    quote = None;
+                }
+                continue
+            } else if ch == '"' || ch == '\'' {
+                quote = Some(ch);
+                buffer.push(ch);
+                continue
+            }
             if ch == CURLY_OPEN {
                 open_braces += 1;
             } else if ch == CURLY_CLOSE {
@@ -1103,7 +1648,7 @@ impl<'a> Parser<'a> {
                 if action == Action::AssemblyStart {
                     action = Action::Assembly;
                 } else if action == Action::Assembly {
-                    statements.push(Statement::Raw(buffer.clone()));
+                    statements.push(Statement::Raw(buffer.clone(), location));
                     buffer.clear();
                 } else {
                     buffer.push(SPACE);
@@ -1113,16 +1658,14 @@ impl<'a> Parser<'a> {
                 if open_braces == close_braces {
                     break
                 }
-                let regex_struct_initializer =
-                    Regex::new(r#"(?x)^\s*(?P<code>.+)\s*\(\{$"#).unwrap();
-                if regex_struct_initializer.is_match(&buffer) {
+                if REGEX_STRUCT_INITIALIZER.is_match(&buffer) {
                     let left_code =
-                        capture_regex(&regex_struct_initializer, &buffer, "code").unwrap();
+                        capture_regex(&REGEX_STRUCT_INITIALIZER, &buffer, "code").unwrap();
                     let right_code = read_until(self.chars, vec![';']);
                     buffer = format!("{left_code}({{{right_code}");
                     close_braces += 1;
                 }
-                statements.push(Statement::Raw(buffer.clone()));
+                statements.push(Statement::Raw(buffer.clone(), location));
                 if action == Action::Assembly {
                     action = Action::None;
                 }
@@ -1130,11 +1673,14 @@ impl<'a> Parser<'a> {
             } else if ch == SLASH {
                 let next_maybe = self.chars.next();
                 if next_maybe == Some(SLASH) {
-                    statements.push(Statement::Raw(format!("// {}", self.parse_comment())));
+                    statements.push(Statement::Raw(
+                        format!("// {}", self.parse_comment()),
+                        location,
+                    ));
                     continue
                 } else if next_maybe == Some(ASTERISK) {
                     for comment in self.parse_multiline_comment().iter() {
-                        statements.push(Statement::Raw(format!("// {comment}")));
+                        statements.push(Statement::Raw(format!("// {comment}"), location));
                     }
                     continue
                 }
@@ -1147,7 +1693,7 @@ impl<'a> Parser<'a> {
                 } else if trim(&buffer) == "for" {
                     open_braces += 1;
                     let for_block = read_until(self.chars, vec!['{']);
-                    statements.push(Statement::Raw(format!("for{for_block}{{")));
+                    statements.push(Statement::Raw(format!("for{for_block}{{"), location));
                     buffer.clear();
                 }
             }
@@ -1169,10 +1715,97 @@ impl<'a> Parser<'a> {
         Ok(Modifier {
             header: self.parse_function_header(comments),
             statements: self.parse_body(),
-            comments: comments.to_vec(),
+            comments: format_natspec(comments),
         })
     }
 
+    /// Sets the local variables of the currently parsed function to its parameters
+    ///
+    /// `header` the header of the function
+    fn set_locals(&mut self, header: &FunctionHeader) {
+        self.locals = header
+            .params
+            .iter()
+            .chain(header.return_params.iter())
+            .map(|param| (param.name.clone(), param.param_type.clone()))
+            .collect();
+        self.solidity_types = header
+            .params
+            .iter()
+            .chain(header.return_params.iter())
+            .map(|param| (param.name.clone(), param.solidity_type.clone()))
+            .collect();
+        self.return_types = header
+            .return_params
+            .iter()
+            .map(|param| param.param_type.clone())
+            .collect();
+        self.temporaries.clear();
+    }
+
+    /// Parses the base constructors called by the constructor, like `Ownable(owner)`
+    ///
+    /// `raw_modifiers` the raw representations of modifiers of the constructor
+    ///
+    /// returns the statements initializing the OpenBrush bases in order of inheritance
+    /// and the comments for the base constructors which can not be translated
+    fn parse_base_initialization(&mut self, raw_modifiers: &[Expression]) -> Vec<Statement> {
+        let mut base_args = Vec::<(OpenBrushBase, Vec<Expression>)>::new();
+        let mut comments = Vec::default();
+        for raw_modifier in raw_modifiers.iter() {
+            if let Expression::Modifier(modifier) = raw_modifier {
+                let modifier = &if modifier.contains('(') {
+                    modifier.to_owned()
+                } else {
+                    format!("{modifier}()")
+                };
+                let name = trim(&modifier[..modifier.find('(').unwrap()]);
+                let base = OpenBrushBase::from_name(&name).filter(|base| self.bases.contains(base));
+                let args = match self.parse_function_call(modifier, true, None) {
+                    Expression::FunctionCall(_, args, ..) => args,
+                    _ => Vec::default(),
+                };
+                match base {
+                    Some(base) if base.initializer().is_some() && !args.is_empty() => {
+                        base_args.push((base, args))
+                    }
+                    Some(_) if args.is_empty() => {}
+                    _ if self.modifiers.contains_key(&name) => {
+                        comments.push(self.not_implemented_comment(format!(
+                            "{NOT_IMPLEMENTED}: modifier {modifier} of the constructor"
+                        )))
+                    }
+                    _ => {
+                        comments.push(self.not_implemented_comment(format!(
+                            "{NOT_IMPLEMENTED}: base constructor {modifier}"
+                        )))
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::default();
+        for base in self.bases.iter() {
+            if let Some(initializer) = base.initializer() {
+                // without arguments the base is initialized with the deployer as in Solidity
+                let args = base_args
+                    .iter()
+                    .find(|(initialized, _)| initialized == base)
+                    .map(|(_, args)| args.clone())
+                    .unwrap_or_else(|| vec![Expression::EnvCaller(Some(String::from("instance")))]);
+                out.push(Statement::FunctionCall(Expression::BaseCall(
+                    initializer.to_owned(),
+                    args,
+                    String::from("instance"),
+                    false,
+                    true,
+                )));
+            }
+        }
+        out.append(&mut comments);
+        out
+    }
+
     /// Parses all modifiers of a function and returns them as a vector of `Modifier` expressions
     ///
     /// `raw_modifiers` the raw representations of modifiers of the function
@@ -1183,11 +1816,34 @@ impl<'a> Parser<'a> {
         let mut out = Vec::default();
         for raw_modifier in raw_modifiers.iter() {
             if let Expression::Modifier(modifier) = raw_modifier {
+                // modifiers without arguments may omit the parentheses
+                let modifier = &if modifier.contains('(') {
+                    modifier.to_owned()
+                } else {
+                    format!("{modifier}()")
+                };
                 let modifier_name =
                     capture_regex(&regex_modifier_name, modifier, "name").unwrap_or_default();
                 if self.modifiers.contains_key(&modifier_name) {
                     let function_call = self.parse_function_call(modifier, false, None);
                     out.push(function_call)
+                } else if let Some(base_modifier) = self
+                    .bases
+                    .iter()
+                    .find_map(|base| base.modifier(&modifier_name))
+                {
+                    self.imports
+                        .insert(String::from("use openbrush::modifiers;"));
+                    if let Expression::FunctionCall(_, args, ..) =
+                        self.parse_function_call(modifier, false, None)
+                    {
+                        out.push(Expression::FunctionCall(
+                            base_modifier.to_owned(),
+                            args,
+                            None,
+                            true,
+                        ))
+                    }
                 }
             }
         }
@@ -1204,11 +1860,17 @@ impl<'a> Parser<'a> {
         let mut out = Vec::default();
 
         while let Some(statement) = iterator.next() {
-            if let Statement::Raw(line_raw) = statement {
+            if let Statement::Raw(line_raw, location) = statement {
+                self.location = Some(*location);
                 out.push(self.parse_statement(line_raw, constructor, &mut stack, &mut iterator));
+            } else {
+                out.push(statement.clone());
             }
         }
+        self.location = None;
 
+        mark_mutable_declarations(&mut out);
+        write_back_storage_pointers(&mut out, &std::mem::take(&mut self.storage_pointers));
         out
     }
 
@@ -1227,17 +1889,25 @@ impl<'a> Parser<'a> {
         stack: &mut VecDeque<Block>,
         iterator: &mut Iter<Statement>,
     ) -> Statement {
-        let mut line = trim(line_raw);
-        line = line.replace(" memory ", " ");
-        line = line.replace(" calldata ", " ");
-        line = line.replace(" storage ", " ");
+        let line = remove_data_locations(&remove_address_payable(&trim(line_raw)));
 
         if line == "_;" {
             return Statement::ModifierBody
+        } else if line.trim_end_matches(SEMICOLON) == "break" {
+            return Statement::Break
+        } else if line.trim_end_matches(SEMICOLON) == "continue" {
+            return Statement::Continue
+        } else if self.is_enumerable_set_call(&line) {
+            return self.not_implemented_comment(format!(
+                "Sol2Ink Not Implemented yet: {line} {ENUMERABLE_SET_ADVICE}"
+            ))
         } else if REGEX_RETURN.is_match(&line) {
             return self.parse_return(&line)
+        } else if REGEX_DELETE.is_match(&line) {
+            return self.parse_delete(&line, constructor)
         } else if REGEX_DECLARE.is_match(&line) {
-            return self.parse_declaration(&line, constructor)
+            let storage_pointer = REGEX_STORAGE_POINTER.is_match(line_raw);
+            return self.parse_declaration(&line, constructor, storage_pointer)
         } else if REGEX_REQUIRE.is_match(&line) {
             return self.parse_require(&line, constructor)
         } else if REGEX_COMMENT.is_match(&line) {
@@ -1294,6 +1964,10 @@ impl<'a> Parser<'a> {
             return self.parse_catch(&line, constructor, stack, iterator)
         } else if REGEX_EMIT.is_match(&line) {
             return self.parse_emit(&line, constructor)
+        } else if REGEX_REVERT_ERROR.is_match(&line) {
+            return self.parse_revert_error(&line, constructor)
+        } else if REGEX_REVERT.is_match(&line) {
+            return self.parse_revert(&line, constructor)
         } else if REGEX_ASSIGN.is_match(&line) {
             return self.parse_assign(&line, constructor)
         } else if REGEX_TERNARY.is_match(&line) {
@@ -1304,10 +1978,16 @@ impl<'a> Parser<'a> {
             return self.parse_binary_operation(&line, constructor, &REGEX_BINARY_PREFIX, None)
         } else if REGEX_FUNCTION_CALL.is_match(&line) {
             let expression = self.parse_function_call(&line, constructor, None);
-            return Statement::FunctionCall(expression)
+            let (expression, statements) = hoist_mapping_args(expression);
+            return prepend_statements(statements, Statement::FunctionCall(expression))
         }
 
-        Statement::Comment(format!("Sol2Ink Not Implemented yet: {}", line.clone()))
+        if line.contains(".delegatecall(") {
+            return self.not_implemented_comment(format!(
+                "{NOT_IMPLEMENTED}: {line} {DELEGATE_CALL_ADVICE}"
+            ))
+        }
+        self.not_implemented_comment(format!("Sol2Ink Not Implemented yet: {}", line.clone()))
     }
 
     /// Parses a return statement
@@ -1317,27 +1997,131 @@ impl<'a> Parser<'a> {
     /// returns the statements in form of `Statement::Return`
     fn parse_return(&mut self, line: &str) -> Statement {
         let raw_output = capture_regex(&REGEX_RETURN, line, "output").unwrap();
-        let output = self.parse_expression(&raw_output, false, None);
+        // chainable calls have no equivalent, the contract is referenced by its `AccountId`
+        if raw_output.trim() == "this" {
+            return self.not_implemented_comment(format!(
+                "{NOT_IMPLEMENTED}: {} ink! contracts can not return themselves, \
+                consider returning `self.env().account_id()`",
+                line.trim()
+            ))
+        }
+        if let Some(statement) = self.parse_fixed_bytes_return(&raw_output) {
+            return statement
+        }
+        let (raw_output, mut statements) = self.extract_increments(&raw_output, false);
+        let mut output = self.parse_expression(&raw_output, false, None);
+        if self.return_types == ["U256"] {
+            output = self.to_u256(output);
+        }
+        // strings and vectors can not be moved out of the storage
+        if let (Expression::Member(_, Some(_)), Some(field_type)) =
+            (&output, self.variable_type(&raw_output))
+        {
+            if field_type == "String" || field_type.starts_with("Vec<") {
+                output = Expression::Literal(quote!(#output.clone()).to_string());
+            }
+        }
+        // computations on mapping values are checked as well as their compound assignments
+        if self.config.checked_arithmetic {
+            if let Expression::Arithmetic(left, right, operation) = output {
+                output = if matches!(
+                    operation,
+                    Operation::Add | Operation::Subtract | Operation::Mul | Operation::Div
+                ) && (matches!(*left, Expression::Mapping(..))
+                    || matches!(*right, Expression::Mapping(..)))
+                {
+                    self.imports
+                        .insert(String::from("use ink_prelude::string::String;"));
+                    Expression::CheckedArithmetic(left, right, operation, false)
+                } else {
+                    Expression::Arithmetic(left, right, operation)
+                };
+            }
+        }
+        let (output, mut hoisted) = hoist_mapping_args(output);
+        statements.append(&mut hoisted);
+
+        prepend_statements(statements, Statement::Return(output))
+    }
 
-        Statement::Return(output)
+    /// Parses a return of a string literal from a function returning `bytesN`
+    ///
+    /// The literal is right-padded with zero bytes or truncated to the size of the returned type
+    ///
+    /// `raw_output` the returned expression
+    ///
+    /// returns the statement in form of `Statement::Return` or None if this is not such return
+    fn parse_fixed_bytes_return(&mut self, raw_output: &str) -> Option<Statement> {
+        let return_type = match self.return_types.as_slice() {
+            [return_type] => return_type,
+            _ => return None,
+        };
+        let size = return_type
+            .strip_prefix("[u8; ")
+            .and_then(|size| size.strip_suffix(']'))
+            .and_then(|size| size.parse::<usize>().ok())?;
+        let value = capture_regex(&REGEX_STRING_LITERAL, raw_output, "value")?;
+
+        if value.len() == size {
+            return Some(Statement::Return(Expression::Literal(format!(
+                "*b\"{value}\""
+            ))))
+        }
+        let mut bytes = value
+            .bytes()
+            .take(size)
+            .map(|byte| format!("{byte:#04x}"))
+            .collect::<Vec<_>>();
+        bytes.resize(size, String::from("0x00"));
+        let adjustment = if value.len() < size {
+            "right-padded with zero bytes"
+        } else {
+            "truncated"
+        };
+        Some(Statement::Group(vec![
+            Statement::Comment(format!(
+                "Sol2Ink: the string literal \"{value}\" is {adjustment} to {size} bytes"
+            )),
+            Statement::Return(Expression::Literal(format!("[{}]", bytes.join(", ")))),
+        ]))
     }
 
     /// Parses a declaration statement
     ///
     /// `line` the soldity declaration statement
+    /// `storage_pointer` whether the declared variable references the storage
     ///
     /// returns the statements in form of `Statement::Declaration`
-    fn parse_declaration(&mut self, line: &str, constructor: bool) -> Statement {
+    fn parse_declaration(
+        &mut self,
+        line: &str,
+        constructor: bool,
+        storage_pointer: bool,
+    ) -> Statement {
         let field_type_raw = capture_regex(&REGEX_DECLARE, line, "field_type").unwrap();
         let field_name = capture_regex(&REGEX_DECLARE, line, "field_name").unwrap();
         let value_raw = capture_regex(&REGEX_DECLARE, line, "value");
+        self.solidity_types
+            .insert(field_name.clone(), field_type_raw.clone());
         let field_type = self.convert_variable_type(field_type_raw);
+        self.locals.insert(field_name.clone(), field_type.clone());
 
         if let Some(value) = value_raw {
-            let expression = self.parse_expression(&value, constructor, None);
-            Statement::Declaration(field_name, field_type, Some(expression))
+            let mut expression = self.parse_expression(&value, constructor, None);
+            if field_type == "U256" {
+                expression = self.to_u256(expression);
+            }
+            // ink! mappings return copies, so the pointer is written back after modification
+            if storage_pointer && matches!(expression, Expression::Mapping(_, _, None)) {
+                self.storage_pointers.insert(field_name.clone());
+            }
+            let (expression, statements) = hoist_mapping_args(expression);
+            prepend_statements(
+                statements,
+                Statement::Declaration(field_name, field_type, Some(expression), false),
+            )
         } else {
-            Statement::Declaration(field_name, field_type, None)
+            Statement::Declaration(field_name, field_type, None, false)
         }
     }
 
@@ -1351,61 +2135,193 @@ impl<'a> Parser<'a> {
         self.imports
             .insert(String::from("use ink_prelude::string::String;"));
 
-        let condition = capture_regex(&REGEX_REQUIRE, line, "condition");
-        let error = capture_regex(&REGEX_REQUIRE, line, "error");
+        let args = split_args(&capture_regex(&REGEX_REQUIRE_ARGS, line, "args").unwrap());
+        let message_raw = args.get(1).map(|message| trim(message));
 
-        let condition = self.parse_condition(&condition.unwrap(), constructor, true, None);
-        let error_output = if constructor {
-            format!(
-                "panic!(\"{}\")",
-                error.unwrap_or_else(|| DEFAULT_ERROR.to_owned())
-            )
-        } else {
-            format!(
-                "return Err(Error::Custom(String::from(\"{}\")))",
-                error.unwrap_or_else(|| DEFAULT_ERROR.to_owned())
-            )
+        let (condition, statements) = self.extract_increments(&args[0], constructor);
+        let condition = self.parse_condition(&condition, constructor, true, None);
+        let error_output = match message_raw {
+            // custom errors are returned like by `revert CustomError(args);`
+            Some(message) if self.is_custom_error(&message) => {
+                let revert = self.parse_revert_error(&format!("revert {message};"), constructor);
+                quote!(#revert).to_string()
+            }
+            Some(message) if !REGEX_STRING_LITERAL.is_match(&message) => {
+                let message = self.parse_message(&message, constructor);
+                if constructor {
+                    format!("panic!(\"{{}}\", {message})")
+                } else {
+                    format!("return Err(Error::Custom({message}))")
+                }
+            }
+            Some(message) => {
+                let error = capture_regex(&REGEX_STRING_LITERAL, &message, "value").unwrap();
+                if constructor {
+                    format!("panic!(\"{error}\")")
+                } else {
+                    format!("return Err(Error::Custom(String::from(\"{error}\")))")
+                }
+            }
+            None if constructor => String::from("panic!()"),
+            None => String::from("return Err(Error::Custom(String::new()))"),
         };
 
-        Statement::Require(condition, error_output)
+        prepend_statements(statements, Statement::Require(condition, error_output))
     }
 
-    /// Parses a solidity condition which is not enclosed in curly brackets
+    /// Returns true if the expression creates a custom error declared in the contract
     ///
-    /// `line_raw` the solidity condition
-    /// `constructor` if the statement is inside a constructor
-    /// `stack` the current statement stack
-    /// `iterator` the iterator of the statements of currently parsed function
+    /// `raw` the solidity expression, like `InsufficientBalance(amount)`
+    fn is_custom_error(&self, raw: &str) -> bool {
+        REGEX_REVERT_ERROR
+            .captures(&format!("revert {raw};"))
+            .is_some_and(|captures| self.errors.contains(&captures["error"]))
+    }
+
+    /// Parses a revert statement with an optional message
     ///
-    /// Return the statement in form of `Statement::If` or `Statement::ElseIf` or `Statement::Else`
-    fn parse_condition_one_line(
-        &mut self,
-        line: &str,
-        constructor: bool,
-        stack: &mut VecDeque<Block>,
-        iterator: &mut Iter<Statement>,
-    ) -> Statement {
-        let keyword = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "keyword").unwrap();
-        let condition_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "condition").unwrap();
-        let then_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "then").unwrap();
-        let then = self.parse_statement(&then_raw, constructor, stack, iterator);
-        let statements = vec![then];
+    /// `line` the solidity revert statement
+    /// `constructor` whether the revert is in a constructor or not
+    ///
+    /// returns the statement in form of `Statement::Revert` with the `Custom` error
+    fn parse_revert(&mut self, line: &str, constructor: bool) -> Statement {
+        self.imports
+            .insert(String::from("use ink_prelude::string::String;"));
 
-        let condition = if keyword == "if" || keyword == "else if" {
-            Some(self.parse_condition(&condition_raw, constructor, false, None))
+        let message_raw = trim(&capture_regex(&REGEX_REVERT, line, "message").unwrap());
+        let message = if message_raw.is_empty() {
+            String::from("String::new()")
+        } else if let Some(literal) = capture_regex(&REGEX_STRING_LITERAL, &message_raw, "value") {
+            format!("String::from(\"{literal}\")")
         } else {
-            None
+            self.parse_message(&message_raw, constructor)
         };
 
-        match condition {
-            Some(condition) => {
-                if keyword == "if" {
-                    Statement::If(condition, statements)
-                } else {
-                    Statement::ElseIf(condition, statements)
-                }
+        Statement::Revert(
+            String::from("Custom"),
+            vec![Expression::Literal(message)],
+            constructor,
+        )
+    }
+
+    /// Parses a revert statement with a custom error
+    ///
+    /// `line` the solidity revert statement
+    /// `constructor` whether the revert is in a constructor or not
+    ///
+    /// returns the statement in form of `Statement::Revert`
+    fn parse_revert_error(&mut self, line: &str, constructor: bool) -> Statement {
+        let error = capture_regex(&REGEX_REVERT_ERROR, line, "error").unwrap();
+        let args_raw = capture_regex(&REGEX_REVERT_ERROR, line, "args").unwrap();
+        let args = split_args(&args_raw)
+            .iter()
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| self.parse_expression(arg, constructor, None))
+            .collect();
+
+        Statement::Revert(error, args, constructor)
+    }
+
+    /// Parses the metadata of a type, like `type(Contract).name` or `type(uint8).max`
+    ///
+    /// `type_raw` the solidity type
+    /// `member` the requested metadata
+    /// `raw` the whole solidity expression
+    ///
+    /// returns the metadata as `Expression`
+    fn parse_type_metadata(&mut self, type_raw: &str, member: &str, raw: &str) -> Expression {
+        let rust_type = self.convert_variable_type(type_raw.to_owned());
+        let integer = REGEX_INT.is_match(type_raw);
+        match member {
+            "name" => {
+                self.imports
+                    .insert(String::from("use ink_prelude::string::String;"));
+                Expression::Literal(format!("String::from(\"{type_raw}\")"))
+            }
+            "max" if integer => Expression::Literal(format!("{rust_type}::MAX")),
+            "min" if integer => Expression::Literal(format!("{rust_type}::MIN")),
+            _ => {
+                Expression::NotImplemented(
+                    self.not_implemented(format!("Sol2Ink Not Implemented yet: {}", trim(raw))),
+                )
+            }
+        }
+    }
+
+    /// Parses a message which is built at runtime, like the message of a `require`
+    ///
+    /// `message_raw` the solidity expression building the message
+    /// `constructor` if the message is built inside a constructor
+    ///
+    /// returns the rust code which evaluates to the message as `String`
+    fn parse_message(&mut self, message_raw: &str, constructor: bool) -> String {
+        let parts_raw = match capture_regex(&REGEX_STRING_CONCAT, message_raw, "packed")
+            .or_else(|| capture_regex(&REGEX_STRING_CONCAT, message_raw, "concat"))
+        {
+            Some(args_raw) => split_args(&args_raw),
+            None => {
+                let message = self.parse_expression(&message_raw.to_owned(), constructor, None);
+                return format!("{}.to_string()", quote!(#message))
             }
-            None => Statement::Else(statements),
+        };
+
+        // the braces are escaped only in the format string
+        let mut text = String::new();
+        let mut format = String::new();
+        let mut args = Vec::<String>::new();
+        for part_raw in parts_raw.iter() {
+            if let Some(literal) = capture_regex(&REGEX_STRING_LITERAL, part_raw, "value") {
+                text.push_str(&literal);
+                format.push_str(&literal.replace('{', "{{").replace('}', "}}"));
+            } else {
+                let part = trim(part_raw);
+                let is_string = self
+                    .variable_type(&part)
+                    .is_some_and(|part_type| part_type == "String");
+                format.push_str(if is_string { "{}" } else { "{:?}" });
+                let expression = self.parse_expression(&part, constructor, None);
+                args.push(quote!(#expression).to_string());
+            }
+        }
+
+        if args.is_empty() {
+            format!("String::from(\"{text}\")")
+        } else {
+            format!("format!(\"{format}\", {})", args.join(", "))
+        }
+    }
+
+    /// Parses a solidity condition which is not enclosed in curly brackets
+    ///
+    /// `line_raw` the solidity condition
+    /// `constructor` if the statement is inside a constructor
+    /// `stack` the current statement stack
+    /// `iterator` the iterator of the statements of currently parsed function
+    ///
+    /// Return the statement in form of `Statement::If` or `Statement::ElseIf` or `Statement::Else`
+    fn parse_condition_one_line(
+        &mut self,
+        line: &str,
+        constructor: bool,
+        stack: &mut VecDeque<Block>,
+        iterator: &mut Iter<Statement>,
+    ) -> Statement {
+        let keyword = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "keyword").unwrap();
+        let condition_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "condition").unwrap();
+        let then_raw = capture_regex(&REGEX_CONDITION_ONE_LINE, line, "then").unwrap();
+        let then = self.parse_statement(&then_raw, constructor, stack, iterator);
+        let statements = vec![then];
+
+        if keyword == "if" {
+            let (condition_raw, increments) = self.extract_increments(&condition_raw, constructor);
+            let condition = self.parse_condition(&condition_raw, constructor, false, None);
+            prepend_statements(increments, Statement::If(condition, statements))
+        } else if keyword == "else if" {
+            let (condition_raw, increments) = self.extract_increments(&condition_raw, constructor);
+            let condition = self.parse_condition(&condition_raw, constructor, false, None);
+            Statement::ElseIf(prepend_to_condition(increments, condition), statements)
+        } else {
+            Statement::Else(statements)
         }
     }
 
@@ -1425,6 +2341,7 @@ impl<'a> Parser<'a> {
         iterator: &mut Iter<Statement>,
     ) -> Statement {
         let condition_raw = capture_regex(&REGEX_IF, line, "condition").unwrap();
+        let (condition_raw, increments) = self.extract_increments(&condition_raw, constructor);
         let condition = self.parse_condition(&condition_raw, constructor, false, None);
         let mut statements = Vec::default();
 
@@ -1436,7 +2353,7 @@ impl<'a> Parser<'a> {
             Statement::IfEnd,
         );
 
-        Statement::If(condition, statements)
+        prepend_statements(increments, Statement::If(condition, statements))
     }
 
     /// Parses a solidity else statement and the statements inside the else block
@@ -1454,8 +2371,10 @@ impl<'a> Parser<'a> {
         stack: &mut VecDeque<Block>,
         iterator: &mut Iter<Statement>,
     ) -> Statement {
-        let condition_raw = capture_regex(&REGEX_ELSE_IF, line, "condition");
-        let condition = self.parse_condition(&condition_raw.unwrap(), constructor, false, None);
+        let condition_raw = capture_regex(&REGEX_ELSE_IF, line, "condition").unwrap();
+        let (condition_raw, increments) = self.extract_increments(&condition_raw, constructor);
+        let condition = self.parse_condition(&condition_raw, constructor, false, None);
+        let condition = prepend_to_condition(increments, condition);
         let mut statements = Vec::default();
 
         self.parse_block(
@@ -1508,7 +2427,25 @@ impl<'a> Parser<'a> {
         inverted: bool,
         enclosed_expressions: Option<HashMap<String, Expression>>,
     ) -> Condition {
-        let (mut left, mut operation, mut right) = if REGEX_BOOLEAN.is_match(line) {
+        // the negation applies to the whole logical expression
+        if inverted && is_compound_condition(line) {
+            let condition = self.parse_expression(line, constructor, enclosed_expressions);
+            return Condition {
+                left: Expression::Enclosed(bx!(condition)),
+                operation: Operation::Not,
+                right: None,
+            }
+        }
+
+        let (mut left, mut operation, mut right) = if let Some(group) = negated_group(line) {
+            // the negation of a group is removed when the condition is inverted
+            let left = self.parse_expression(&group, constructor, enclosed_expressions);
+            if inverted {
+                (left, Operation::Not, None)
+            } else {
+                (Expression::Enclosed(bx!(left)), Operation::Not, None)
+            }
+        } else if REGEX_BOOLEAN.is_match(line) {
             let left_raw = capture_regex(&REGEX_BOOLEAN, line, "left").unwrap();
             let operation_raw = capture_regex(&REGEX_BOOLEAN, line, "operation").unwrap();
             let right_raw = capture_regex(&REGEX_BOOLEAN, line, "right").unwrap();
@@ -1516,6 +2453,15 @@ impl<'a> Parser<'a> {
             let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
             let operation = *OPERATIONS.get(&operation_raw).unwrap();
             let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
+            let (left, right) = match operation {
+                Operation::Equal
+                | Operation::NotEqual
+                | Operation::LessThan
+                | Operation::LessThanEqual
+                | Operation::GreaterThan
+                | Operation::GreaterThanEqual => self.coerce_u256(left, right),
+                _ => (left, right),
+            };
 
             (left, operation, Some(right))
         } else {
@@ -1542,13 +2488,17 @@ impl<'a> Parser<'a> {
                 .insert(String::from("use openbrush::traits::AccountIdExt;\n"));
         }
 
+        if inverted {
+            operation = operation.negate();
+            // the negation applies to the whole logical expression
+            if operation == Operation::Not && matches!(left, Expression::Logical(..)) {
+                left = Expression::Enclosed(bx!(left));
+            }
+        }
+
         Condition {
             left,
-            operation: if inverted {
-                operation.negate()
-            } else {
-                operation
-            },
+            operation,
             right,
         }
     }
@@ -1648,6 +2598,14 @@ impl<'a> Parser<'a> {
             Statement::WhileEnd,
         );
 
+        if let Some((variable, range)) =
+            counting_range(&assignment, &condition, &modification, &mut statements)
+        {
+            return Statement::For(variable, range, statements)
+        }
+        // `continue` skips the rest of the body, but not the modification of the loop variable
+        insert_before_continue(&mut statements, &modification);
+
         Statement::While(
             Some(bx!(assignment)),
             condition,
@@ -1680,13 +2638,25 @@ impl<'a> Parser<'a> {
         );
 
         let next_statement = iterator.next().unwrap();
-        let condition = if let Statement::Raw(content) = next_statement {
+        let condition = if let Statement::Raw(content, location) = next_statement {
+            self.location = Some(*location);
             let condition_raw = capture_regex(&REGEX_WHILE, content, "condition").unwrap();
             self.parse_expression(&condition_raw, constructor, None)
         } else {
             panic!("Expected Raw statement after do block")
         };
 
+        // `continue` skips the rest of the body, but not the condition of the loop
+        let exit = Statement::If(
+            Condition {
+                left: Expression::Enclosed(bx!(condition.clone())),
+                operation: Operation::Not,
+                right: None,
+            },
+            vec![Statement::Break],
+        );
+        insert_before_continue(&mut statements, &exit);
+
         Statement::Loop(None, condition, None, statements)
     }
 
@@ -1736,7 +2706,8 @@ impl<'a> Parser<'a> {
         until: Statement,
     ) {
         while let Some(statement_raw) = iterator.next() {
-            if let Statement::Raw(line_raw) = statement_raw {
+            if let Statement::Raw(line_raw, location) = statement_raw {
+                self.location = Some(*location);
                 let statement = self.parse_statement(line_raw, constructor, stack, iterator);
                 if statement == until {
                     break
@@ -1748,7 +2719,8 @@ impl<'a> Parser<'a> {
     }
 
     /// Parses a solidity assembly statement and the statements inside the assembly block
-    /// A notice is inserted to the beginning and to the end of the assembly block
+    /// A notice is inserted to the beginning and to the end of the assembly block,
+    /// blocks delegating the call, like the fallback of a proxy, are preceded by the ink! alternative
     /// All statements of the assembly block are parsed as a `Statement::Comment`
     ///
     /// `stack` the current statement stack
@@ -1756,7 +2728,7 @@ impl<'a> Parser<'a> {
     ///
     /// Return the statement in form of `Statement::Group`
     fn parse_assembly(
-        &self,
+        &mut self,
         stack: &mut VecDeque<Block>,
         iterator: &mut Iter<Statement>,
     ) -> Statement {
@@ -1766,7 +2738,7 @@ impl<'a> Parser<'a> {
         )));
 
         for statement_raw in iterator.by_ref() {
-            if let Statement::Raw(content_raw) = statement_raw {
+            if let Statement::Raw(content_raw, _) = statement_raw {
                 let content = trim(content_raw);
                 if content == "}" {
                     stack.pop_back();
@@ -1782,6 +2754,16 @@ impl<'a> Parser<'a> {
             "<<< Please handle assembly blocks manually",
         )));
 
+        // proxies forward the calls to the implementation in assembly
+        if statements.iter().any(
+            |statement| matches!(statement, Statement::Comment(content) if content.contains("delegatecall(")),
+        ) {
+            let advice = self.not_implemented_comment(format!(
+                "{NOT_IMPLEMENTED}: delegatecall, {DELEGATE_CALL_ADVICE}"
+            ));
+            statements.insert(0, advice);
+        }
+
         Statement::Group(statements)
     }
 
@@ -1863,7 +2845,10 @@ impl<'a> Parser<'a> {
 
         let left = self.parse_expression(&left_raw, constructor, None);
         let operation = *OPERATIONS.get(&operation_raw).unwrap();
-        let right = self.parse_expression(&right_raw, constructor, None);
+        let mut right = self.parse_expression(&right_raw, constructor, None);
+        if self.is_u256(&left) {
+            right = self.to_u256(right);
+        }
 
         if REGEX_BINARY_PREFIX.is_match(&right_raw) {
             let value_raw = capture_regex(&REGEX_BINARY_PREFIX, &right_raw, "value").unwrap();
@@ -1881,28 +2866,59 @@ impl<'a> Parser<'a> {
             return Statement::Group(vec![assign, arithmetic])
         }
 
-        if let Expression::Mapping(name, indices, None) = left {
-            let converted_operation = match operation {
-                Operation::AddAssign => Operation::Add,
-                Operation::MulAssign => Operation::Mul,
-                Operation::DivAssign => Operation::Div,
-                Operation::SubtractAssign => Operation::Subtract,
-                _ => operation,
-            };
-            let right_mapping = match converted_operation {
-                Operation::Add | Operation::Mul | Operation::Div | Operation::Subtract => {
-                    Some(bx!(Expression::Arithmetic(
-                        bx!(Expression::Mapping(name.clone(), indices.clone(), None,)),
-                        bx!(right),
-                        converted_operation,
-                    )))
-                }
-                _ => Some(bx!(right)),
-            };
-            Statement::FunctionCall(Expression::Mapping(name, indices, right_mapping))
-        } else {
-            Statement::Assign(left, right, operation)
+        let compound = matches!(
+            operation,
+            Operation::AddAssign
+                | Operation::SubtractAssign
+                | Operation::MulAssign
+                | Operation::DivAssign
+        );
+        let statement = assign(left, right, operation);
+        if !self.config.checked_arithmetic || !compound {
+            return statement
+        }
+
+        match statement {
+            Statement::FunctionCall(Expression::Mapping(name, indices, Some(value))) => {
+                let value = match *value {
+                    Expression::Arithmetic(left, right, operation) => {
+                        self.imports
+                            .insert(String::from("use ink_prelude::string::String;"));
+                        Expression::CheckedArithmetic(left, right, operation, constructor)
+                    }
+                    value => value,
+                };
+                Statement::FunctionCall(Expression::Mapping(name, indices, Some(bx!(value))))
+            }
+            statement => statement,
+        }
+    }
+
+    /// Parses a solidity delete statement, which resets the target to its default value
+    ///
+    /// `line` the solidity delete statement
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// Return the statement in form of an assignment of the default value
+    fn parse_delete(&mut self, line: &str, constructor: bool) -> Statement {
+        let target_raw = capture_regex(&REGEX_DELETE, line, "target").unwrap();
+        if self
+            .variable_type(&target_raw)
+            .and_then(|target_type| mapping_value_type(target_type))
+            .is_some()
+        {
+            return self.not_implemented_comment(format!(
+                "Sol2Ink Not Implemented yet: {line} ink! mappings can not be cleared, \
+                consider versioning the mapping by adding a version counter to its key"
+            ))
         }
+        let target = self.parse_expression(&target_raw, constructor, None);
+        if let Expression::Mapping(mapping, indices, None) = target {
+            return Statement::FunctionCall(Expression::MappingRemove(mapping, indices))
+        }
+        let default = Expression::Literal(String::from("Default::default()"));
+
+        assign(target, default, Operation::Assign)
     }
 
     /// Parses a solidity ternary operation
@@ -1949,13 +2965,39 @@ impl<'a> Parser<'a> {
                     .insert(String::from("use openbrush::traits::ZERO_ADDRESS;"));
             } else if expression == &Expression::EnvCaller(None) {
                 return Expression::EnvCaller(Some(selector!(constructor)))
+            } else if expression == &Expression::EnvAccountId(None) {
+                return Expression::EnvAccountId(Some(selector!(constructor)))
+            } else if expression == &Expression::EnvBalance(None) {
+                return Expression::EnvBalance(Some(selector!(constructor)))
             } else if expression == &Expression::TransferredValue(None) {
                 return Expression::TransferredValue(Some(selector!(constructor)))
+            } else if expression == &Expression::BlockTimestamp(None) {
+                return Expression::BlockTimestamp(Some(selector!(constructor)))
+            } else if expression == &Expression::BlockNumber(None) {
+                return Expression::BlockNumber(Some(selector!(constructor)))
+            } else if let Expression::NotImplemented(message) = expression {
+                return Expression::NotImplemented(self.not_implemented(message.clone()))
             }
 
             return expression.clone()
+        } else if let Some(int_type) = self.convert_int(raw) {
+            return Expression::Literal(int_type)
         } else if let Some(new_type) = TYPES.get(raw.as_str()) {
             return Expression::Literal(new_type.0.to_owned())
+        } else if self.is_enumerable_set_call(raw) {
+            return Expression::NotImplemented(self.not_implemented(format!(
+                "Sol2Ink Not Implemented yet: {} {ENUMERABLE_SET_ADVICE}",
+                trim(raw)
+            )))
+        }
+
+        if let Some(type_raw) = capture_regex(&REGEX_TYPE_METADATA, raw, "type") {
+            let member = capture_regex(&REGEX_TYPE_METADATA, raw, "member").unwrap();
+            return self.parse_type_metadata(&type_raw, &member, raw)
+        }
+
+        if let Some(expression) = self.parse_external_call(raw, constructor) {
+            return expression
         }
 
         if let Some(expression) = enclosed_expressions.clone().unwrap_or_default().get(raw) {
@@ -1967,6 +3009,34 @@ impl<'a> Parser<'a> {
             }
         }
 
+        if let Some((elements_raw, index_raw)) = split_array_literal(raw) {
+            let elements = split_args(&elements_raw)
+                .iter()
+                .map(|element| {
+                    self.parse_expression(element, constructor, enclosed_expressions.clone())
+                })
+                .collect::<Vec<_>>();
+            let array = Expression::Literal(quote!([#(#elements),*]).to_string());
+            return match index_raw {
+                Some(index_raw) => {
+                    let index =
+                        self.parse_expression(&index_raw, constructor, enclosed_expressions);
+                    Expression::ArrayIndex(bx!(array), bx!(index))
+                }
+                None => array,
+            }
+        }
+
+        if let Some(elements_raw) = split_tuple_literal(raw) {
+            let elements = elements_raw
+                .iter()
+                .map(|element| {
+                    self.parse_expression(element, constructor, enclosed_expressions.clone())
+                })
+                .collect::<Vec<_>>();
+            return Expression::Literal(quote!((#(#elements),*)).to_string())
+        }
+
         let extracted = self.extract_parentheses(raw, constructor, false);
         if extracted.1 > 0 {
             return self.parse_expression(&extracted.0, constructor, Some(extracted.2))
@@ -2098,6 +3168,12 @@ impl<'a> Parser<'a> {
             let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
             let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
             let operation = *OPERATIONS.get(&operation_raw).unwrap();
+            if operation == Operation::Pow {
+                if let Some(power) = self.parse_u256_pow(&left_raw, &right_raw, &left, &right) {
+                    return power
+                }
+            }
+            let (left, right) = self.coerce_u256(left, right);
 
             return Expression::Arithmetic(bx!(left), bx!(right), operation)
         }
@@ -2117,6 +3193,9 @@ impl<'a> Parser<'a> {
             let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
             let operation = *OPERATIONS.get(&operation_raw).unwrap();
             let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
+            if let Some(helper_call) = self.parse_byte_array_operation(&left, operation, &right) {
+                return helper_call
+            }
 
             return Expression::Logical(bx!(left), operation, bx!(right))
         }
@@ -2125,8 +3204,20 @@ impl<'a> Parser<'a> {
             return self.parse_ternary(raw, constructor, enclosed_expressions)
         }
 
-        if REGEX_BOOLEAN.is_match(raw) {
+        if REGEX_BOOLEAN.is_match(raw) || raw.trim_start().starts_with(EXCLAMAITON) {
             let condition = self.parse_condition(raw, constructor, false, enclosed_expressions);
+            if let Some(right) = &condition.right {
+                if let Some(helper_call) =
+                    self.parse_byte_array_operation(&condition.left, condition.operation, right)
+                {
+                    return helper_call
+                }
+                if let Some(shift) =
+                    self.parse_wrapping_shift(&condition.left, condition.operation, right)
+                {
+                    return shift
+                }
+            }
             return Expression::Condition(bx!(condition))
         }
 
@@ -2135,6 +3226,20 @@ impl<'a> Parser<'a> {
         if regex_with_selector.is_match(raw) {
             let left_raw = capture_regex(&regex_with_selector, raw, "left").unwrap();
             let right_raw = capture_regex(&regex_with_selector, raw, "right").unwrap();
+            if self.enums.contains(&left_raw) {
+                return Expression::Literal(format!(
+                    "{}::{}",
+                    left_raw.to_case(Pascal),
+                    self.enum_variant(&right_raw)
+                ))
+            }
+            if right_raw == "length" {
+                let array = self.parse_expression(&left_raw, constructor, enclosed_expressions);
+                return Expression::Length(bx!(array))
+            }
+            if let Some(message) = self.attached_library_call(&left_raw, &right_raw) {
+                return Expression::NotImplemented(self.not_implemented(message))
+            }
             let left = self.parse_expression(&left_raw, constructor, enclosed_expressions.clone());
             let right = self.parse_expression(&right_raw, constructor, enclosed_expressions);
 
@@ -2204,6 +3309,31 @@ impl<'a> Parser<'a> {
                 }
             }
 
+            if indices.len() == 1 && self.is_array(&mapping_raw) {
+                return Expression::ArrayIndex(bx!(mapping), bx!(indices.remove(0)))
+            }
+
+            // the indices following the keys of the mapping index the array stored in it
+            if let Some(key_count) = self
+                .variable_type(&mapping_raw)
+                .and_then(|mapping_type| mapping_key_count(mapping_type))
+            {
+                // nested mappings are a single mapping with all keys in ink!
+                if indices.len() < key_count {
+                    return Expression::NotImplemented(self.not_implemented(format!(
+                        "{NOT_IMPLEMENTED}: {mapping_raw} indexed by {} of {key_count} keys",
+                        indices.len()
+                    )))
+                }
+                if indices.len() > key_count {
+                    let array_indices = indices.split_off(key_count);
+                    return array_indices.into_iter().fold(
+                        Expression::Mapping(bx!(mapping), indices, None),
+                        |array, index| Expression::ArrayIndex(bx!(array), bx!(index)),
+                    )
+                }
+            }
+
             return Expression::Mapping(bx!(mapping), indices, None)
         }
 
@@ -2233,7 +3363,16 @@ impl<'a> Parser<'a> {
 
         if let Some(contract_field) = self.storage.get(raw) {
             if contract_field.constant {
-                return Expression::Constant(contract_field.name.clone())
+                let constant = contract_field.name.to_case(UpperSnake);
+                return match contract_field.field_type.as_str() {
+                    "&str" => {
+                        self.imports
+                            .insert(String::from("use ink_prelude::string::String;"));
+                        Expression::Literal(format!("String::from({constant})"))
+                    }
+                    "&[u8]" => Expression::Literal(format!("{constant}.to_vec()")),
+                    _ => Expression::Constant(contract_field.name.clone()),
+                }
             }
         }
 
@@ -2384,6 +3523,75 @@ impl<'a> Parser<'a> {
         Expression::Ternary(bx!(condition), bx!(if_true), bx!(if_false))
     }
 
+    /// Extracts increments and decrements used inside of an expression
+    ///
+    /// Post-increments are substituted with a temporary variable holding the original value
+    /// and pre-increments are substituted with the variable itself, the increments are then
+    /// returned as separate statements which need to be executed before the expression
+    ///
+    /// `raw` the raw representation of the expression
+    /// `constructor` if the expression is inside a constructor
+    ///
+    /// Return `0` the expression without increments
+    /// Return `1` the statements to execute before the expression
+    fn extract_increments(&mut self, raw: &str, constructor: bool) -> (String, Vec<Statement>) {
+        let mut out = raw.to_owned();
+        let mut statements = Vec::default();
+
+        while let Some(captures) = captures_outside_strings(&REGEX_POSTFIX_INCREMENT, &out.clone())
+        {
+            let value_raw = captures.name("value").unwrap().as_str();
+            let operation_raw = captures.name("operation").unwrap().as_str();
+            let value_name = value_raw
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join("_");
+            // the same variable may be incremented several times in the function
+            let count = self.temporaries.entry(value_name.clone()).or_insert(0);
+            let tmp_name = match *count {
+                0 => format!("{value_name}_tmp"),
+                count => format!("{value_name}_tmp_{count}"),
+            };
+            *count += 1;
+            let value = self.parse_expression(&value_raw.to_owned(), constructor, None);
+            statements.push(Statement::Declaration(
+                tmp_name.clone(),
+                String::new(),
+                Some(value),
+                false,
+            ));
+            statements.push(self.parse_increment(value_raw, operation_raw, constructor));
+            out.replace_range(captures.get(0).unwrap().range(), &tmp_name);
+        }
+
+        while let Some(captures) = captures_outside_strings(&REGEX_PREFIX_INCREMENT, &out.clone()) {
+            let value_raw = captures.name("value").unwrap().as_str();
+            let operation_raw = captures.name("operation").unwrap().as_str();
+            statements.push(self.parse_increment(value_raw, operation_raw, constructor));
+            out.replace_range(captures.get(0).unwrap().range(), value_raw);
+        }
+
+        (out, statements)
+    }
+
+    /// Parses an increment or decrement of a value as an assignment
+    ///
+    /// `value_raw` the raw representation of the incremented value
+    /// `operation_raw` the raw representation of the operation (`++` or `--`)
+    /// `constructor` if the statement is inside a constructor
+    ///
+    /// Return the statement in form of `Statement::Assign` or mapping insert
+    fn parse_increment(
+        &mut self,
+        value_raw: &str,
+        operation_raw: &str,
+        constructor: bool,
+    ) -> Statement {
+        let operation = if operation_raw == "++" { "+=" } else { "-=" };
+        self.parse_assign(&format!("{value_raw} {operation} 1;"), constructor)
+    }
+
     /// Parses a solidity binary operation
     ///
     /// `line` the solidity representation of the binary operation statement
@@ -2403,7 +3611,12 @@ impl<'a> Parser<'a> {
         let operation_raw =
             capture_regex(regex, line, "operation").unwrap_or_else(|| String::from("="));
 
+        let statement_level = enclosed_expressions.is_none();
         let member = self.parse_expression(&member_raw, constructor, enclosed_expressions);
+        // values of mappings are read, incremented and inserted back
+        if statement_level && matches!(member, Expression::Mapping(_, _, None)) {
+            return self.parse_increment(&member_raw, &operation_raw, constructor)
+        }
         let original_operation = *OPERATIONS.get(&operation_raw).unwrap();
         let operation = match original_operation {
             Operation::AddOne => Operation::AddAssign,
@@ -2414,6 +3627,57 @@ impl<'a> Parser<'a> {
         Statement::Assign(member, Expression::Literal(String::from("1")), operation)
     }
 
+    /// Parses a call of a function of another contract through its interface
+    ///
+    /// `raw` the solidity representation of the call, e.g. `IToken(token).transfer(to, amount)`
+    /// `constructor` if the call is inside a constructor
+    ///
+    /// returns `Expression::ExternalCall` or `None` if the expression is not a call through an interface
+    fn parse_external_call(&mut self, raw: &str, constructor: bool) -> Option<Expression> {
+        let (interface, address_raw, function_name, args_raw) =
+            match capture_regex(&REGEX_EXTERNAL_CALL, raw, "interface") {
+                Some(interface) => {
+                    (
+                        interface,
+                        capture_regex(&REGEX_EXTERNAL_CALL, raw, "address").unwrap(),
+                        capture_regex(&REGEX_EXTERNAL_CALL, raw, "function_name").unwrap(),
+                        capture_regex(&REGEX_EXTERNAL_CALL, raw, "args").unwrap(),
+                    )
+                }
+                None => {
+                    // a call on a field which stores the address of a contract
+                    let member = capture_regex(&REGEX_MEMBER_CALL, raw, "member")?;
+                    if self.locals.contains_key(&member) {
+                        return None
+                    }
+                    (
+                        self.interface_fields.get(&member)?.clone(),
+                        member,
+                        capture_regex(&REGEX_MEMBER_CALL, raw, "function_name").unwrap(),
+                        capture_regex(&REGEX_MEMBER_CALL, raw, "args").unwrap(),
+                    )
+                }
+            };
+        if !is_balanced(&args_raw) {
+            return None
+        }
+
+        let address = self.parse_expression(&address_raw, constructor, None);
+        let args = split_args(&args_raw)
+            .iter()
+            .filter(|arg| !arg.is_empty())
+            .map(|arg| self.parse_expression(arg, constructor, None))
+            .collect();
+
+        Some(Expression::ExternalCall(
+            interface,
+            function_name,
+            bx!(address),
+            args,
+            constructor,
+        ))
+    }
+
     /// Parses a solidity function call
     ///
     /// `line` the solidity representation of the function call
@@ -2427,6 +3691,10 @@ impl<'a> Parser<'a> {
         constructor: bool,
         enclosed_expressions: Option<HashMap<String, Expression>>,
     ) -> Expression {
+        if let Some(expression) = self.parse_external_call(line, constructor) {
+            return expression
+        }
+
         let function_name_raw = capture_regex(&REGEX_FUNCTION_CALL, line, "function_name").unwrap();
         let args_raw = capture_regex(&REGEX_FUNCTION_CALL, line, "args").unwrap();
         let mut args = Vec::<Expression>::new();
@@ -2434,6 +3702,32 @@ impl<'a> Parser<'a> {
         let mut open_parentheses = 0;
         let mut close_parenthesis = 0;
 
+        if let Some(expression) =
+            self.parse_bytes32_conversion(&function_name_raw, &args_raw, constructor)
+        {
+            return expression
+        }
+
+        if self.is_address_integer_cast(&function_name_raw, &args_raw) {
+            return Expression::NotImplemented(
+                self.not_implemented(format!("Sol2Ink Not Implemented yet: {}", trim(line))),
+            )
+        }
+
+        // contracts are referenced by their address, payable addresses are addresses as well
+        if REGEX_INTERFACE_TYPE.is_match(&function_name_raw) || function_name_raw == "payable" {
+            return self.parse_expression(&args_raw, constructor, enclosed_expressions)
+        }
+
+        if let Some(int_type) = self.convert_int(&function_name_raw) {
+            let value = self.parse_expression(&args_raw, constructor, enclosed_expressions);
+            return if int_type == "U256" {
+                Expression::Cast(true, String::from("U256::from"), bx!(value))
+            } else {
+                Expression::Cast(false, int_type, bx!(value))
+            }
+        }
+
         if TYPES.contains_key(&function_name_raw.as_str()) {
             let the_type = TYPES.get(&function_name_raw.as_str()).unwrap();
             if let Some(unique_cast) = the_type.1 {
@@ -2478,16 +3772,59 @@ impl<'a> Parser<'a> {
         }
 
         if !trim(&buffer).is_empty() {
-            args.push(self.parse_expression(&buffer, constructor, enclosed_expressions));
+            args.push(self.parse_expression(&trim(&buffer), constructor, enclosed_expressions));
+        }
+
+        if let Some(structure) = self.structs.get(&function_name_raw) {
+            let args = structure
+                .fields
+                .iter()
+                .zip(args)
+                .map(|(field, arg)| Expression::StructArg(field.name.clone(), bx!(arg)))
+                .collect();
+            return Expression::StructInit(function_name_raw, args)
+        }
+
+        if let Some((base, (base_function, fallible))) = self
+            .bases
+            .iter()
+            .find_map(|base| Some((base, base.function(&function_name_raw)?)))
+        {
+            let mut args = args;
+            if base.takes_data(&function_name_raw) {
+                args.push(Expression::Literal(String::from("Vec::<u8>::new()")));
+                self.imports
+                    .insert(String::from("use ink_prelude::vec::Vec;\n"));
+            }
+            return Expression::BaseCall(
+                base_function.to_owned(),
+                args,
+                selector!(constructor),
+                fallible,
+                constructor,
+            )
         }
 
+        // overloads are renamed, so the call is resolved by the count of its arguments
+        let function_name_raw = match self.overloads.get(&(function_name_raw.clone(), args.len())) {
+            Some(Some(overload)) => overload.clone(),
+            Some(None) => {
+                return Expression::NotImplemented(self.not_implemented(format!(
+                    "{NOT_IMPLEMENTED}: {} calls one of the overloads with {} parameters",
+                    trim(line),
+                    args.len()
+                )))
+            }
+            None => function_name_raw,
+        };
+
         let selector = if self.functions.get(&function_name_raw).is_some() {
             Some(selector!(constructor))
         } else {
             None
         };
 
-        return Expression::FunctionCall(
+        Expression::FunctionCall(
             function_name_raw.clone(),
             args,
             selector,
@@ -2495,41 +3832,267 @@ impl<'a> Parser<'a> {
         )
     }
 
-    /// Converts solidity variable type to ink! variable type (eg. address -> AccountId, uint -> u128, ...)
+    /// Parses a conversion between `bytes32` and `string`
     ///
-    /// `arg_type` solidity argument type
-    /// `imports` the set of imports of the contract
+    /// `string(abi.encodePacked(value))` of a `bytes32` value is converted with the
+    /// `bytes32_to_string` helper and `bytes32(bytes(value))` with the `string_to_bytes32` helper
     ///
-    /// return the converted type
-    fn convert_variable_type(&mut self, arg_type: String) -> String {
-        // removes array braces from the type
-        let (no_array_arg_type, is_vec) =
-            if arg_type.substring(arg_type.len() - 2, arg_type.len()) == "[]" {
-                (arg_type.substring(0, arg_type.len() - 2), true)
-            } else {
-                (arg_type.as_str(), false)
-            };
-        let regex_mapping: Regex = Regex::new(
-            r#"(?x)^\s*mapping\(
-                (?P<type_from>.+?)=>
-                (?P<type_to>.+)
-                \)\s*$"#,
-        )
-        .unwrap();
-        if regex_mapping.is_match(&arg_type) {
-            self.imports
-                .insert(String::from("use openbrush::storage::Mapping;\n"));
-            let mut from_raw = capture_regex(&regex_mapping, &arg_type, "type_from").unwrap();
-            let mut to_raw = capture_regex(&regex_mapping, &arg_type, "type_to").unwrap();
-
-            let mut from_vec = vec![self.convert_variable_type(from_raw)];
-            while regex_mapping.is_match(&to_raw) {
-                from_raw = capture_regex(&regex_mapping, &to_raw, "type_from").unwrap();
-                to_raw = capture_regex(&regex_mapping, &to_raw, "type_to").unwrap();
-                from_vec.push(self.convert_variable_type(from_raw));
+    /// `function_name_raw` the type to which we convert
+    /// `args_raw` the raw representation of the converted value
+    /// `constructor` if the expression is inside a constructor
+    ///
+    /// Return the expression in form of `Expression::HelperCall` or None if this is not the conversion
+    fn parse_bytes32_conversion(
+        &mut self,
+        function_name_raw: &str,
+        args_raw: &str,
+        constructor: bool,
+    ) -> Option<Expression> {
+        let (helper, value_raw) = match function_name_raw {
+            "string" => {
+                let value_raw = capture_regex(&REGEX_ENCODE_PACKED, args_raw, "value")?;
+                if self.variable_type(&value_raw)? != "[u8; 32]" {
+                    return None
+                }
+                (Helper::Bytes32ToString, value_raw)
             }
+            "bytes32" => {
+                let value_raw = capture_regex(&REGEX_BYTES_CAST, args_raw, "value")
+                    .or_else(|| capture_regex(&REGEX_ENCODE_PACKED, args_raw, "value"))?;
+                (Helper::StringToBytes32, value_raw)
+            }
+            _ => return None,
+        };
 
-            let to = self.convert_variable_type(to_raw);
+        self.imports
+            .insert(String::from("use ink_prelude::string::String;"));
+        self.helpers.insert(helper);
+        let value = self.parse_expression(&value_raw, constructor, None);
+        Some(Expression::HelperCall(helper, vec![value]))
+    }
+
+    /// Parses a power with `U256` operands, which do not support casting with `as`
+    ///
+    /// `left_raw` the raw representation of the base
+    /// `right_raw` the raw representation of the exponent
+    /// `left` the base
+    /// `right` the exponent
+    ///
+    /// Return the expression in form of `Expression::Literal` or None if none of the operands is `U256`
+    fn parse_u256_pow(
+        &self,
+        left_raw: &str,
+        right_raw: &str,
+        left: &Expression,
+        right: &Expression,
+    ) -> Option<Expression> {
+        let is_u256 = |raw: &str| self.variable_type(raw).is_some_and(|var| var == "U256");
+        if is_u256(left_raw) {
+            Some(Expression::Literal(
+                quote!(#left.pow(U256::from(#right))).to_string(),
+            ))
+        } else if is_u256(right_raw) {
+            Some(Expression::Literal(
+                quote!(#left.pow(#right.as_u32())).to_string(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// returns true if the expression is a `U256` value
+    ///
+    /// `expression` the expression to check
+    fn is_u256(&self, expression: &Expression) -> bool {
+        match expression {
+            Expression::Member(name, _) => {
+                self.variable_type(name).is_some_and(|var| var == "U256")
+            }
+            Expression::Mapping(mapping, _, None) => {
+                match mapping.as_ref() {
+                    Expression::Member(name, _) => {
+                        self.variable_type(name)
+                            .is_some_and(|mapping_type| mapping_type.ends_with(", U256>"))
+                    }
+                    _ => false,
+                }
+            }
+            Expression::Cast(true, cast_type, _) => cast_type == "U256::from",
+            Expression::Arithmetic(left, right, _)
+            | Expression::CheckedArithmetic(left, right, ..) => {
+                self.is_u256(left) || self.is_u256(right)
+            }
+            Expression::Enclosed(expression) => self.is_u256(expression),
+            _ => false,
+        }
+    }
+
+    /// Converts an integer to `U256`, which is not created from integer literals
+    /// and has no arithmetic or comparison with the other integers
+    ///
+    /// `expression` the converted expression
+    ///
+    /// returns the expression wrapped in `U256::from` if it is an integer other than `U256`
+    fn to_u256(&self, expression: Expression) -> Expression {
+        let integer = match &expression {
+            // integer literals which do not fit in `i32` are parsed as members
+            Expression::Literal(literal) | Expression::Member(literal, None)
+                if literal.trim().parse::<u128>().is_ok() =>
+            {
+                true
+            }
+            Expression::Member(name, _) => {
+                self.variable_type(name).is_some_and(|var| {
+                    matches!(var.as_str(), "u8" | "u16" | "u32" | "u64" | "u128")
+                })
+            }
+            Expression::Cast(false, ..) | Expression::Length(_) => true,
+            _ => false,
+        };
+        if integer {
+            Expression::Cast(true, String::from("U256::from"), bx!(expression))
+        } else {
+            expression
+        }
+    }
+
+    /// Converts the integer operand to `U256` if the other operand is `U256`
+    ///
+    /// `left` the left operand
+    /// `right` the right operand
+    ///
+    /// returns the operands
+    fn coerce_u256(&self, left: Expression, right: Expression) -> (Expression, Expression) {
+        if !self.config.u256 {
+            (left, right)
+        } else if self.is_u256(&left) && !self.is_u256(&right) {
+            (left, self.to_u256(right))
+        } else if self.is_u256(&right) && !self.is_u256(&left) {
+            (self.to_u256(left), right)
+        } else {
+            (left, right)
+        }
+    }
+
+    /// Parses a bitwise operation on `bytesN` values, which are arrays not implementing the operators
+    ///
+    /// `left` the left operand
+    /// `operation` the operation
+    /// `right` the right operand, the shift amount in case of shifts
+    ///
+    /// Return the expression in form of `Expression::HelperCall` or None if the operands are not byte arrays
+    fn parse_byte_array_operation(
+        &mut self,
+        left: &Expression,
+        operation: Operation,
+        right: &Expression,
+    ) -> Option<Expression> {
+        let helper = match operation {
+            Operation::ShiftLeft => Helper::ShlBytes,
+            Operation::ShiftRight => Helper::ShrBytes,
+            Operation::BitwiseAnd => Helper::AndBytes,
+            Operation::BitwiseOr => Helper::OrBytes,
+            Operation::Xor => Helper::XorBytes,
+            _ => return None,
+        };
+        match left {
+            Expression::Member(name, _) if self.variable_type(name)?.starts_with("[u8;") => {}
+            _ => return None,
+        }
+
+        let right = match helper {
+            Helper::ShlBytes | Helper::ShrBytes => {
+                Expression::Cast(false, String::from("u128"), bx!(right.clone()))
+            }
+            _ => right.clone(),
+        };
+        self.helpers.insert(helper);
+        Some(Expression::HelperCall(helper, vec![left.clone(), right]))
+    }
+
+    /// Parses a shift of integers as `wrapping_shl` or `wrapping_shr` if configured
+    ///
+    /// `left` the shifted value
+    /// `operation` the operation
+    /// `right` the shift amount
+    ///
+    /// Return the expression in form of `Expression::Literal` or None if this is not a wrapping shift
+    fn parse_wrapping_shift(
+        &self,
+        left: &Expression,
+        operation: Operation,
+        right: &Expression,
+    ) -> Option<Expression> {
+        if !self.config.wrapping_shifts {
+            return None
+        }
+        let method = match operation {
+            Operation::ShiftLeft => quote!(wrapping_shl),
+            Operation::ShiftRight => quote!(wrapping_shr),
+            _ => return None,
+        };
+        let right = Expression::Cast(false, String::from("u32"), bx!(right.clone()));
+        Some(Expression::Literal(
+            quote!(#left.#method(#right)).to_string(),
+        ))
+    }
+
+    /// Returns true if the function call is a cast between `address` and `uint160`
+    ///
+    /// `function_name_raw` the type to which we convert
+    /// `args_raw` the raw representation of the converted value
+    fn is_address_integer_cast(&self, function_name_raw: &str, args_raw: &str) -> bool {
+        let args = args_raw.trim();
+        match function_name_raw {
+            "address" => {
+                args.starts_with("uint160(")
+                    || args.starts_with("uint160 (")
+                    || self.solidity_types.get(args) == Some(&String::from("uint160"))
+            }
+            "uint160" => {
+                args.starts_with("address(")
+                    || SPECIFIC_EXPRESSION.get(args) == Some(&Expression::EnvCaller(None))
+                    || self.variable_type(args) == Some(&String::from("AccountId"))
+            }
+            _ => false,
+        }
+    }
+
+    /// Converts solidity variable type to ink! variable type (eg. address -> AccountId, uint -> u128, ...)
+    ///
+    /// `arg_type` solidity argument type
+    /// `imports` the set of imports of the contract
+    ///
+    /// return the converted type
+    fn convert_variable_type(&mut self, arg_type: String) -> String {
+        // removes array braces from the type
+        let (no_array_arg_type, is_vec) =
+            if arg_type.substring(arg_type.len() - 2, arg_type.len()) == "[]" {
+                (arg_type.substring(0, arg_type.len() - 2), true)
+            } else {
+                (arg_type.as_str(), false)
+            };
+        let regex_mapping: Regex = Regex::new(
+            r#"(?x)^\s*mapping\(
+                (?P<type_from>.+?)=>
+                (?P<type_to>.+)
+                \)\s*$"#,
+        )
+        .unwrap();
+        if regex_mapping.is_match(&arg_type) {
+            self.imports
+                .insert(String::from("use openbrush::storage::Mapping;\n"));
+            let mut from_raw = capture_regex(&regex_mapping, &arg_type, "type_from").unwrap();
+            let mut to_raw = capture_regex(&regex_mapping, &arg_type, "type_to").unwrap();
+
+            let mut from_vec = vec![self.convert_variable_type(from_raw)];
+            while regex_mapping.is_match(&to_raw) {
+                from_raw = capture_regex(&regex_mapping, &to_raw, "type_from").unwrap();
+                to_raw = capture_regex(&regex_mapping, &to_raw, "type_to").unwrap();
+                from_vec.push(self.convert_variable_type(from_raw));
+            }
+
+            let to = self.convert_variable_type(to_raw);
 
             let from = if from_vec.len() > 1 {
                 format!("({})", from_vec.join(", "))
@@ -2538,9 +4101,23 @@ impl<'a> Parser<'a> {
             };
             return format!("Mapping<{}, {}>", from, to)
         }
-        let output_type = match convert_int(no_array_arg_type.to_string()).as_str() {
-            str if str.contains("uint") => str.replace("uint", "u"),
-            str if str.contains("int") => str.replace("int", "i"),
+        if let Some(size) = capture_regex(&REGEX_FIXED_ARRAY, &arg_type, "size") {
+            let element_raw = capture_regex(&REGEX_FIXED_ARRAY, &arg_type, "element").unwrap();
+            return format!("[{}; {size}]", self.convert_variable_type(element_raw))
+        }
+        let output_type = match no_array_arg_type {
+            str if REGEX_INT.is_match(str) => self.convert_int(str).unwrap(),
+            str if REGEX_INTERFACE_TYPE.is_match(str) => String::from("AccountId"),
+            str if str.starts_with("EnumerableSet.") => {
+                self.imports
+                    .insert(String::from("use ink_prelude::vec::Vec;\n"));
+                let element = match str.trim_start_matches("EnumerableSet.") {
+                    "AddressSet" => String::from("AccountId"),
+                    "UintSet" => self.convert_int("uint256").unwrap(),
+                    _ => String::from("[u8; 32]"),
+                };
+                format!("Vec<{element}>")
+            }
             str if TYPES.contains_key(str) => {
                 let the_type = TYPES.get(str).unwrap();
                 if let Some(import) = the_type.2 {
@@ -2548,9 +4125,11 @@ impl<'a> Parser<'a> {
                 }
                 the_type.0.to_string()
             }
+            // enums are assembled with PascalCase names
+            str if self.enums.contains(str) => str.to_case(Pascal),
             _ => no_array_arg_type.to_owned(),
         };
-        return if is_vec {
+        if is_vec {
             self.imports
                 .insert(String::from("use ink_prelude::vec::Vec;\n"));
             format!("Vec<{}>", output_type)
@@ -2559,6 +4138,98 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Converts solidity integer type to ink! integer type, the sizes are rounded up to the closest
+    /// rust integer, integers wider than 128 bits are `u128`/`i128` or `U256` if set in the config,
+    /// signed integers stay `i128` and are reported
+    ///
+    /// `arg_type` solidity type
+    ///
+    /// returns the converted type or None if the type is not an integer
+    fn convert_int(&mut self, arg_type: &str) -> Option<String> {
+        let int_type = capture_regex(&REGEX_INT, arg_type, "int_type")?;
+        let int_size_raw = capture_regex(&REGEX_INT, arg_type, "int_size").unwrap();
+        let int_size = if int_size_raw.is_empty() {
+            256
+        } else {
+            int_size_raw.parse::<i32>().unwrap()
+        };
+        let unsigned = int_type == "uint";
+        if int_size > 128 && unsigned && self.config.u256 {
+            self.imports
+                .insert(String::from("use primitive_types::U256;\n"));
+            return Some(String::from("U256"))
+        }
+        if int_size > 128 && self.config.u256 {
+            // `primitive_types` has no signed 256-bit integer
+            self.not_implemented(format!(
+                "{NOT_IMPLEMENTED}: int{int_size} is translated to i128, which does not cover its range"
+            ));
+            // the type is converted again when the same statement is parsed further
+            self.diagnostics.dedup();
+        }
+        let rust_size = match int_size {
+            i if i <= 8 => 8,
+            i if i <= 16 => 16,
+            i if i <= 32 => 32,
+            i if i <= 64 => 64,
+            _ => 128,
+        };
+
+        Some(format!("{}{rust_size}", if unsigned { "u" } else { "i" }))
+    }
+
+    /// returns true if the expression calls a function of an `EnumerableSet` storage field
+    ///
+    /// `line` the expression to check
+    fn is_enumerable_set_call(&self, line: &str) -> bool {
+        REGEX_ENUMERABLE_SET_CALL
+            .captures(line)
+            .filter(|captures| self.enumerable_sets.contains(&captures["set"]))
+            .filter(|captures| is_balanced(&captures["args"]))
+            .is_some()
+    }
+
+    /// Finds the library attached by `using Lib for *` whose function is called on a variable,
+    /// libraries are not translated, so their functions can not be called
+    ///
+    /// `receiver_raw` the raw representation of the receiver, like `total` of `total.add(a)`
+    /// `call_raw` the raw representation of the call, like `add(a)`
+    ///
+    /// returns the message of the generated TODO or None if no library is attached to all types
+    fn attached_library_call(&self, receiver_raw: &str, call_raw: &str) -> Option<String> {
+        let function_name = capture_regex(&REGEX_FUNCTION_CALL, call_raw, "function_name")?;
+        self.variable_type(receiver_raw)?;
+        if self.global_libraries.is_empty() {
+            return None
+        }
+
+        Some(format!(
+            "{NOT_IMPLEMENTED}: {}.{} calls {}.{function_name}, libraries are not translated",
+            trim(receiver_raw),
+            trim(call_raw),
+            self.global_libraries.join(" or "),
+        ))
+    }
+
+    /// returns true if the variable with the given name is an array
+    ///
+    /// `name` the name of the variable (local or storage)
+    fn is_array(&self, name: &str) -> bool {
+        self.variable_type(name)
+            .map(|field_type| field_type.starts_with("Vec<") || field_type.starts_with('['))
+            .unwrap_or(false)
+    }
+
+    /// returns the ink! type of the variable with the given name
+    ///
+    /// `name` the name of the variable (local or storage)
+    fn variable_type(&self, name: &str) -> Option<&String> {
+        let name = name.trim();
+        self.locals
+            .get(name)
+            .or_else(|| self.storage.get(name).map(|field| &field.field_type))
+    }
+
     /// returns the selctor of the field (function or a variable)
     ///
     /// `constructor` if we access this field from a constructor
@@ -2566,7 +4237,8 @@ impl<'a> Parser<'a> {
     ///
     /// returns Some if the field needs a selector, None otherwise
     fn get_selector(&self, constructor: bool, field_name: &String) -> Option<String> {
-        if self.storage.contains_key(field_name) {
+        // local variables shadow the storage fields
+        if self.storage.contains_key(field_name) && !self.locals.contains_key(field_name) {
             Some(selector!(constructor))
         } else {
             None
@@ -2587,6 +4259,457 @@ fn capture_regex(regex: &Regex, line: &str, capture_name: &str) -> Option<String
     })
 }
 
+/// Extracts `require` guards which are repeated in at least three functions into helper functions
+/// and replaces the guards with the calls of the helper functions
+///
+/// Only guards which do not use local variables or parameters of the function are extracted
+///
+/// `functions` the functions of the contract, the helper functions are appended to them
+fn extract_repeated_requires(functions: &mut Vec<Function>) {
+    let regex_message = Regex::new(r#"String::from\("(?P<message>.+)"\)"#).unwrap();
+    let mut guards = Vec::<(Statement, Vec<String>)>::new();
+
+    for function in functions.iter() {
+        for statement in function.body.iter() {
+            if let Statement::Require(condition, error) = statement {
+                // messages built at runtime may use the locals as well
+                if condition_uses_locals(condition) || !regex_message.is_match(error) {
+                    continue
+                }
+                match guards.iter_mut().find(|(guard, _)| guard == statement) {
+                    Some((_, users)) if !users.contains(&function.header.name) => {
+                        users.push(function.header.name.clone())
+                    }
+                    Some(_) => {}
+                    None => guards.push((statement.clone(), vec![function.header.name.clone()])),
+                }
+            }
+        }
+    }
+
+    let mut helpers = Vec::<Function>::new();
+    for (guard, users) in guards.into_iter().filter(|(_, users)| users.len() >= 3) {
+        let message = match &guard {
+            Statement::Require(_, error) => capture_regex(&regex_message, error, "message"),
+            _ => None,
+        }
+        .map(|message| message.replace(|c: char| !c.is_alphanumeric(), " "))
+        .filter(|message| !message.trim().is_empty());
+        let base_name = match message {
+            Some(message) => format!("validate_{}", snake_case(&message)),
+            None => format!("validate_{}", helpers.len()),
+        };
+        // different guards may have the same message
+        let mut name = base_name.clone();
+        let mut suffix = 0;
+        while functions
+            .iter()
+            .chain(helpers.iter())
+            .any(|function| function.header.name == name)
+        {
+            suffix += 1;
+            name = format!("{base_name}_{suffix}");
+        }
+        let call = Statement::FunctionCall(Expression::FunctionCall(
+            name.clone(),
+            Vec::default(),
+            Some(String::from("self")),
+            false,
+        ));
+
+        for function in functions
+            .iter_mut()
+            .filter(|function| users.contains(&function.header.name))
+        {
+            for statement in function.body.iter_mut() {
+                if statement == &guard {
+                    *statement = call.clone();
+                }
+            }
+        }
+
+        helpers.push(Function {
+            header: FunctionHeader {
+                name,
+                view: true,
+                comments: vec![format!(
+                    "Guard extracted from functions `{}`",
+                    users.join("`, `")
+                )],
+                ..Default::default()
+            },
+            body: vec![guard],
+            ..Default::default()
+        });
+    }
+
+    functions.append(&mut helpers);
+}
+
+/// Returns true if the condition uses local variables or parameters of the function
+///
+/// `condition` the condition to check
+fn condition_uses_locals(condition: &Condition) -> bool {
+    uses_locals(&condition.left) || condition.right.as_ref().is_some_and(uses_locals)
+}
+
+/// Returns true if the expression uses local variables or parameters of the function
+///
+/// `expression` the expression to check
+fn uses_locals(expression: &Expression) -> bool {
+    match expression {
+        // the untranslated code may use anything
+        Expression::Member(_, None) | Expression::NotImplemented(_) => true,
+        Expression::Arithmetic(left, right, _)
+        | Expression::ArrayIndex(left, right)
+        | Expression::CheckedArithmetic(left, right, ..)
+        | Expression::Logical(left, _, right)
+        | Expression::WithSelector(left, right) => uses_locals(left) || uses_locals(right),
+        Expression::Cast(_, _, expression)
+        | Expression::Enclosed(expression)
+        | Expression::IsZero(expression)
+        | Expression::Length(expression)
+        | Expression::NewArray(_, expression)
+        | Expression::StructArg(_, expression) => uses_locals(expression),
+        Expression::Condition(condition) => condition_uses_locals(condition),
+        Expression::ExternalCall(_, _, address, args, _) => {
+            uses_locals(address) || args.iter().any(uses_locals)
+        }
+        Expression::BaseCall(_, args, ..)
+        | Expression::FunctionCall(_, args, _, _)
+        | Expression::HelperCall(_, args)
+        | Expression::StructInit(_, args) => args.iter().any(uses_locals),
+        Expression::Mapping(mapping, indices, insert) => {
+            uses_locals(mapping)
+                || indices.iter().any(uses_locals)
+                || insert.as_ref().is_some_and(|insert| uses_locals(insert))
+        }
+        Expression::MappingRemove(mapping, indices) => {
+            uses_locals(mapping) || indices.iter().any(uses_locals)
+        }
+        Expression::Ternary(condition, if_true, if_false) => {
+            condition_uses_locals(condition) || uses_locals(if_true) || uses_locals(if_false)
+        }
+        Expression::BlockNumber(_)
+        | Expression::BlockTimestamp(_)
+        | Expression::Constant(_)
+        | Expression::EnvAccountId(_)
+        | Expression::EnvBalance(_)
+        | Expression::EnvCaller(_)
+        | Expression::Literal(_)
+        | Expression::Member(_, Some(_))
+        | Expression::Modifier(_)
+        | Expression::TransferredValue(_)
+        | Expression::ZeroAddressInto => false,
+    }
+}
+
+/// Returns true if the expression calls a function of the contract, which borrows the contract
+///
+/// `expression` the expression to check
+fn calls_contract(expression: &Expression) -> bool {
+    let condition_calls_contract = |condition: &Condition| {
+        calls_contract(&condition.left) || condition.right.as_ref().is_some_and(calls_contract)
+    };
+    match expression {
+        Expression::BaseCall(..) | Expression::FunctionCall(_, _, Some(_), _) => true,
+        Expression::Arithmetic(left, right, _)
+        | Expression::ArrayIndex(left, right)
+        | Expression::CheckedArithmetic(left, right, ..)
+        | Expression::Logical(left, _, right)
+        | Expression::WithSelector(left, right) => calls_contract(left) || calls_contract(right),
+        Expression::Cast(_, _, expression)
+        | Expression::Enclosed(expression)
+        | Expression::IsZero(expression)
+        | Expression::Length(expression)
+        | Expression::NewArray(_, expression)
+        | Expression::StructArg(_, expression) => calls_contract(expression),
+        Expression::Condition(condition) => condition_calls_contract(condition),
+        Expression::ExternalCall(_, _, address, args, _) => {
+            calls_contract(address) || args.iter().any(calls_contract)
+        }
+        Expression::FunctionCall(_, args, None, _)
+        | Expression::HelperCall(_, args)
+        | Expression::StructInit(_, args) => args.iter().any(calls_contract),
+        Expression::Mapping(mapping, indices, insert) => {
+            calls_contract(mapping)
+                || indices.iter().any(calls_contract)
+                || insert.as_ref().is_some_and(|insert| calls_contract(insert))
+        }
+        Expression::MappingRemove(mapping, indices) => {
+            calls_contract(mapping) || indices.iter().any(calls_contract)
+        }
+        Expression::Ternary(condition, if_true, if_false) => {
+            condition_calls_contract(condition)
+                || calls_contract(if_true)
+                || calls_contract(if_false)
+        }
+        _ => false,
+    }
+}
+
+/// Evaluates the statements in a block before the condition, which is the only place to execute
+/// them before the condition of `else if`
+///
+/// `statements` the statements to be executed first
+/// `condition` the condition to evaluate after them
+///
+/// returns the condition itself if there is nothing to prepend, the block as condition otherwise
+fn prepend_to_condition(statements: Vec<Statement>, condition: Condition) -> Condition {
+    if statements.is_empty() {
+        return condition
+    }
+    let condition = Expression::Condition(bx!(condition));
+    Condition {
+        left: Expression::Literal(quote!({ #(#statements)* #condition }).to_string()),
+        operation: Operation::True,
+        right: None,
+    }
+}
+
+/// Finds the first match of the regex which is not inside of a string literal
+///
+/// `regex` the regex to use
+/// `raw` the Solidity code
+///
+/// returns the captures of the match or None if there is no such match
+fn captures_outside_strings<'a>(regex: &Regex, raw: &'a str) -> Option<regex::Captures<'a>> {
+    regex.captures_iter(raw).find(|captures| {
+        let start = captures.get(0).unwrap().start();
+        let mut quote = None;
+        let mut escaped = false;
+        for ch in raw[..start].chars() {
+            match (quote, ch) {
+                (Some(_), '\\') if !escaped => {
+                    escaped = true;
+                    continue
+                }
+                (Some(open), _) if ch == open && !escaped => quote = None,
+                (None, '"' | '\'') => quote = Some(ch),
+                _ => {}
+            }
+            escaped = false;
+        }
+        quote.is_none()
+    })
+}
+
+/// Groups the statements with the statement which follows them
+///
+/// `statements` the statements to be executed first
+/// `statement` the statement to execute after them
+///
+/// returns the statement itself if there is nothing to prepend, `Statement::Group` otherwise
+fn prepend_statements(mut statements: Vec<Statement>, statement: Statement) -> Statement {
+    if statements.is_empty() {
+        statement
+    } else {
+        statements.push(statement);
+        Statement::Group(statements)
+    }
+}
+
+/// Moves the mapping reads passed as arguments to a function of the contract to local variables,
+/// so the mapping is not read while the contract is borrowed by the call
+///
+/// `expression` the expression which may be a function call
+///
+/// returns the function call reading the local variables and the declarations of these variables
+fn hoist_mapping_args(expression: Expression) -> (Expression, Vec<Statement>) {
+    let (function_name, args, selector, external) = match expression {
+        Expression::FunctionCall(function_name, args, Some(selector), external) => {
+            (function_name, args, selector, external)
+        }
+        expression => return (expression, Vec::default()),
+    };
+    let mut statements = Vec::default();
+    let mut names = Vec::<String>::default();
+    let args = args
+        .into_iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            match arg {
+                Expression::Mapping(mapping, indices, None) => {
+                    let mut name = match mapping.as_ref() {
+                        Expression::Member(mapping_name, _) => format!("{mapping_name}_value"),
+                        _ => String::from("value"),
+                    };
+                    if names.contains(&name) {
+                        name = format!("{name}_{index}");
+                    }
+                    names.push(name.clone());
+                    statements.push(Statement::Declaration(
+                        name.clone(),
+                        String::new(),
+                        Some(Expression::Mapping(mapping, indices, None)),
+                        false,
+                    ));
+                    Expression::Member(name, None)
+                }
+                arg => arg,
+            }
+        })
+        .collect();
+
+    (
+        Expression::FunctionCall(function_name, args, Some(selector), external),
+        statements,
+    )
+}
+
+/// Splits the arguments of a function call by the commas which are not nested in brackets or strings
+///
+/// `args` the raw arguments of the function call
+fn split_args(args: &str) -> Vec<String> {
+    let mut out = Vec::<String>::new();
+    let mut buffer = String::new();
+    let mut depth = 0;
+    let mut quote_maybe = None;
+
+    for ch in args.chars() {
+        match ch {
+            '"' | '\'' if quote_maybe.is_none() => quote_maybe = Some(ch),
+            _ if quote_maybe == Some(ch) => quote_maybe = None,
+            PARENTHESIS_OPEN | BRACKET_OPEN if quote_maybe.is_none() => depth += 1,
+            PARENTHESIS_CLOSE | BRACKET_CLOSE if quote_maybe.is_none() => depth -= 1,
+            COMMA if quote_maybe.is_none() && depth == 0 => {
+                out.push(buffer.trim().to_owned());
+                buffer.clear();
+                continue
+            }
+            _ => {}
+        }
+        buffer.push(ch);
+    }
+    out.push(buffer.trim().to_owned());
+
+    out
+}
+
+/// Returns true if the condition is joined by `&&` or `||` which are not nested in brackets or strings
+///
+/// `condition` the raw condition
+fn is_compound_condition(condition: &str) -> bool {
+    let mut depth = 0;
+    let mut quote_maybe = None;
+    let mut previous = None;
+
+    for ch in condition.chars() {
+        match ch {
+            '"' | '\'' if quote_maybe.is_none() => quote_maybe = Some(ch),
+            _ if quote_maybe == Some(ch) => quote_maybe = None,
+            PARENTHESIS_OPEN | BRACKET_OPEN if quote_maybe.is_none() => depth += 1,
+            PARENTHESIS_CLOSE | BRACKET_CLOSE if quote_maybe.is_none() => depth -= 1,
+            '&' | '|' if quote_maybe.is_none() && depth == 0 && previous == Some(ch) => return true,
+            _ => {}
+        }
+        previous = Some(ch);
+    }
+
+    false
+}
+
+/// Splits an inline array literal, which may be indexed, like `[10, 20, 30][i]`
+///
+/// `raw` the raw expression
+///
+/// returns the elements of the array and the index if it is indexed,
+/// or None if the expression is not an array literal
+fn split_array_literal(raw: &str) -> Option<(String, Option<String>)> {
+    let raw = raw.trim().strip_prefix(BRACKET_OPEN)?;
+    let mut depth = 0;
+    let end = raw.char_indices().find_map(|(position, ch)| {
+        match ch {
+            PARENTHESIS_OPEN | BRACKET_OPEN => depth += 1,
+            BRACKET_CLOSE if depth == 0 => return Some(position),
+            PARENTHESIS_CLOSE | BRACKET_CLOSE => depth -= 1,
+            _ => {}
+        }
+        None
+    })?;
+    let elements = raw[..end].to_owned();
+    let rest = raw[end + 1..].trim();
+    if rest.is_empty() {
+        return Some((elements, None))
+    }
+    let index = rest
+        .strip_prefix(BRACKET_OPEN)?
+        .strip_suffix(BRACKET_CLOSE)?;
+    is_balanced(index).then(|| (elements, Some(index.to_owned())))
+}
+
+/// Splits a tuple literal, like `(a, b)` of `return (a, b);`
+///
+/// `raw` the raw expression
+///
+/// returns the elements of the tuple or None if the expression is not a tuple literal
+fn split_tuple_literal(raw: &str) -> Option<Vec<String>> {
+    let content = raw
+        .trim()
+        .strip_prefix(PARENTHESIS_OPEN)?
+        .strip_suffix(PARENTHESIS_CLOSE)?;
+    if !is_balanced(content) {
+        return None
+    }
+    let elements = split_args(content);
+    (elements.len() > 1).then_some(elements)
+}
+
+/// Returns the content of a negated group spanning the whole condition, like `a && b` of `!(a && b)`
+///
+/// `condition` the raw condition
+fn negated_group(condition: &str) -> Option<String> {
+    let group = condition.trim().strip_prefix(EXCLAMAITON)?.trim();
+    let content = group
+        .strip_prefix(PARENTHESIS_OPEN)?
+        .strip_suffix(PARENTHESIS_CLOSE)?;
+    is_balanced(content).then(|| content.to_owned())
+}
+
+/// Returns true if the brackets in the expression are balanced and none is closed before opened
+///
+/// `expression` the expression to check
+fn is_balanced(expression: &str) -> bool {
+    let mut depth = 0;
+    for ch in expression.chars() {
+        match ch {
+            PARENTHESIS_OPEN | BRACKET_OPEN => depth += 1,
+            PARENTHESIS_CLOSE | BRACKET_CLOSE if depth == 0 => return false,
+            PARENTHESIS_CLOSE | BRACKET_CLOSE => depth -= 1,
+            _ => {}
+        }
+    }
+    depth == 0
+}
+
+/// Converts a solidity hex or string literal to a rust byte slice literal
+///
+/// `literal` the solidity literal, e.g. `hex"deadbeef"` or `"abc"`
+///
+/// returns the byte slice, e.g. `&[0xde, 0xad, 0xbe, 0xef]` or `b"abc"`, or `None` if the expression is not a literal
+fn byte_slice(literal: &str) -> Option<String> {
+    let literal = literal.trim();
+    if let Some(hex) = literal
+        .strip_prefix("hex\"")
+        .and_then(|hex| hex.strip_suffix('"'))
+    {
+        let mut hex = hex.replace('_', "");
+        // the odd digit belongs to the first byte
+        if hex.len() % 2 == 1 {
+            hex.insert(0, '0');
+        }
+        let bytes = (0..hex.len())
+            .step_by(2)
+            .map(|index| format!("0x{}", hex.get(index..index + 2).unwrap_or_default()))
+            .collect::<Vec<_>>();
+        Some(format!("&[{}]", bytes.join(", ")))
+    } else if literal.starts_with('"') && literal.ends_with('"') {
+        Some(format!("b{literal}"))
+    } else {
+        None
+    }
+}
+
 /// Returns true if expression passed is a literal
 ///
 /// `expression` the expression to check
@@ -2600,6 +4723,32 @@ fn is_literal(expression: &String) -> bool {
         || expression == "false"
 }
 
+/// Removes `payable` from `address payable` types, both are `AccountId` in ink!
+///
+/// `raw` the Solidity code
+///
+/// returns the code with `address payable` types replaced by `address`
+fn remove_address_payable(raw: &str) -> String {
+    REGEX_ADDRESS_PAYABLE
+        .replace_all(raw, "address")
+        .to_string()
+}
+
+/// Removes the data locations of types, which do not exist in ink!
+///
+/// `raw` the Solidity code, like `uint256[] memory values`
+///
+/// returns the code without `memory`, `storage` and `calldata` keywords, string literals are kept
+fn remove_data_locations(raw: &str) -> String {
+    REGEX_DATA_LOCATION
+        .replace_all(raw, |captures: &regex::Captures| {
+            captures
+                .name("string")
+                .map_or(String::new(), |string| string.as_str().to_owned())
+        })
+        .to_string()
+}
+
 /// Parses attributes of a function like payable, external, view
 ///
 /// `attributes` the raw representation of the attributes of the function
@@ -2607,12 +4756,676 @@ fn is_literal(expression: &String) -> bool {
 /// returns 0. external 1. view 2. payable
 fn parse_function_attributes(attributes: &str) -> (bool, bool, bool) {
     let external = attributes.contains("external") || attributes.contains("public");
-    let view = attributes.contains("view") || attributes.contains("pure");
     let payable = attributes.contains("payable");
+    // payable functions always change the state
+    let view = (attributes.contains("view") || attributes.contains("pure")) && !payable;
 
     (external, view, payable)
 }
 
+/// Adds the members of a base contract declared in the same file to the inheriting contract
+///
+/// members overridden by the inheriting contract are not added, the constructor of the base
+/// is added only as the `init` function of an abstract contract, overriding functions
+/// without modifiers inherit the modifiers of the overridden function
+///
+/// `contract` the inheriting contract
+/// `base` the base contract
+fn merge_base_contract(contract: &mut Contract, base: &Contract) {
+    macro_rules! merge_members {
+        ($members:ident, $($name:ident).+) => {
+            let names = contract
+                .$members
+                .iter()
+                .map(|member| member.$($name).+.clone())
+                .collect::<HashSet<_>>();
+            let inherited = base
+                .$members
+                .iter()
+                .filter(|member| !names.contains(&member.$($name).+))
+                .cloned()
+                .collect::<Vec<_>>();
+            contract.$members.splice(0..0, inherited);
+        };
+    }
+
+    merge_members!(fields, name);
+    merge_members!(events, name);
+    merge_members!(errors, name);
+    merge_members!(enums, name);
+    merge_members!(structs, name);
+    merge_members!(modifiers, header.name);
+
+    // overriding functions which do not restate the modifiers keep the guards of the base
+    for function in contract.functions.iter_mut() {
+        if !function.header.modifiers.is_empty() {
+            continue
+        }
+        if let Some(declaration) = base
+            .functions
+            .iter()
+            .find(|declaration| declaration.header.name == function.header.name)
+        {
+            function.header.modifiers = declaration.header.modifiers.clone();
+        }
+    }
+    merge_members!(functions, header.name);
+    contract.imports.extend(base.imports.iter().cloned());
+}
+
+/// Formats the NatSpec tags of the comments as rust documentation
+///
+/// `@title`, `@notice` and `@dev` are replaced by their text, `@param` and `@return` are
+/// collected into the `# Parameters` and `# Returns` sections following the other comments,
+/// lines without a tag continue the parameter or return value above them
+///
+/// `comments` the comments of the documented item
+fn format_natspec(comments: &[String]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut params = Vec::<String>::new();
+    let mut returns = Vec::<String>::new();
+    // the section continued by the lines without a tag
+    let mut section: Option<&mut Vec<String>> = None;
+
+    for comment in comments.iter() {
+        let indent = &comment[..comment.len() - comment.trim_start().len()];
+        let (tag, text) = match comment.trim().strip_prefix('@') {
+            Some(tagged) => {
+                let (tag, text) = tagged
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((tagged, ""));
+                (Some(tag), text.trim())
+            }
+            None => (None, comment.trim()),
+        };
+        match tag {
+            Some("param") => {
+                let (name, description) =
+                    text.split_once(char::is_whitespace).unwrap_or((text, ""));
+                params.push(format!(
+                    "{indent}- `{}`: {}",
+                    snake_case(name),
+                    description.trim()
+                ));
+                section = Some(&mut params);
+            }
+            Some("return") => {
+                returns.push(format!("{indent}- {text}"));
+                section = Some(&mut returns);
+            }
+            Some("title" | "notice" | "dev") => {
+                out.push(format!("{indent}{text}"));
+                section = None;
+            }
+            None if section.is_some() => {
+                let item = section.as_mut().and_then(|items| items.last_mut()).unwrap();
+                item.push(' ');
+                item.push_str(text);
+            }
+            _ => {
+                out.push(comment.clone());
+                section = None;
+            }
+        }
+    }
+
+    let indent = if comments.iter().all(|comment| comment.starts_with(SPACE)) {
+        " "
+    } else {
+        ""
+    };
+    for (heading, items) in [("Parameters", params), ("Returns", returns)] {
+        if !items.is_empty() {
+            if !out.is_empty() {
+                out.push(String::new());
+            }
+            out.push(format!("{indent}# {heading}"));
+            out.extend(items);
+        }
+    }
+
+    out
+}
+
+/// Parses the list of base contracts following the name of the contract
+///
+/// `inheritance` the raw list of base contracts, like `is ERC20, Ownable(msg.sender)`
+///
+/// returns the names of the base contracts
+fn parse_base_contracts(inheritance: &str) -> Vec<String> {
+    match trim(inheritance).strip_prefix("is ") {
+        Some(bases) => {
+            split_args(bases)
+                .iter()
+                .map(|base| base.split('(').next().unwrap().trim().to_owned())
+                .filter(|base| !base.is_empty())
+                .collect()
+        }
+        None => Vec::default(),
+    }
+}
+
+/// Renames the overloaded functions, as Rust does not support overloading
+///
+/// The overload with the least parameters keeps its name, the others are suffixed with the name
+/// of their last parameter, or with their position among the overloads if that name is taken
+///
+/// `headers` the headers of the functions of a contract or an interface
+///
+/// returns the names of the overloads by their original name and count of parameters,
+/// None if more overloads have the same count of parameters
+fn rename_overloads<'a>(
+    headers: impl Iterator<Item = &'a mut FunctionHeader>,
+) -> HashMap<(String, usize), Option<String>> {
+    let mut headers = headers.collect::<Vec<_>>();
+    let mut names = headers
+        .iter()
+        .map(|header| header.name.clone())
+        .collect::<HashSet<_>>();
+    // the overloads are grouped in the order of their declaration to keep the naming stable
+    let mut overloads = Vec::<(String, Vec<usize>)>::new();
+    for (index, header) in headers.iter().enumerate() {
+        match overloads.iter_mut().find(|(name, _)| name == &header.name) {
+            Some((_, indices)) => indices.push(index),
+            None => overloads.push((header.name.clone(), vec![index])),
+        }
+    }
+
+    for (name, indices) in overloads.iter().filter(|(_, indices)| indices.len() > 1) {
+        let mut kept = indices[0];
+        for index in indices.iter() {
+            if headers[*index].params.len() < headers[kept].params.len() {
+                kept = *index;
+            }
+        }
+        for (position, index) in indices
+            .iter()
+            .enumerate()
+            .filter(|(_, index)| **index != kept)
+        {
+            let mut renamed = headers[*index]
+                .params
+                .last()
+                .map(|param| format!("{name}With{}", param.name.to_case(Pascal)))
+                .unwrap_or_default();
+            let mut suffix = position;
+            while renamed.is_empty() || names.contains(&renamed) {
+                renamed = format!("{name}{suffix}");
+                suffix += 1;
+            }
+            names.insert(renamed.clone());
+            headers[*index].comments.push(format!(
+                "Sol2Ink: renamed from the overloaded function `{name}`, which changes its selector"
+            ));
+            headers[*index].name = renamed;
+        }
+    }
+
+    let mut resolved = HashMap::new();
+    for (name, indices) in overloads.iter().filter(|(_, indices)| indices.len() > 1) {
+        for index in indices.iter() {
+            resolved
+                .entry((name.clone(), headers[*index].params.len()))
+                .and_modify(|overload: &mut Option<String>| *overload = None)
+                .or_insert_with(|| Some(headers[*index].name.clone()));
+        }
+    }
+    resolved
+}
+
+/// Removes the mapping fields of structs and documents the removal on the struct
+///
+/// Values of structs are encoded as a whole, which is not possible for a `Mapping`
+///
+/// `structs` the structs defined in the contract
+fn remove_struct_mappings(structs: &mut [Struct]) {
+    for structure in structs.iter_mut() {
+        let (mappings, fields) = structure
+            .fields
+            .drain(..)
+            .partition::<Vec<StructField>, _>(|field| field.field_type.starts_with("Mapping<"));
+        structure.fields = fields;
+        for mapping in mappings.iter() {
+            structure.comments.push(format!(
+                "Sol2Ink: field `{}` of type `{}` was removed, mappings can not be encoded inside of a struct, move it to the contract storage with the key of the struct as a part of its key",
+                mapping.name, mapping.field_type
+            ));
+        }
+    }
+}
+
+/// Adds notes about the storage costs to mappings with large values
+///
+/// Every access to a value of a `Mapping` loads or stores the whole value,
+/// so vectors and structs with many fields are expensive to keep inside a mapping
+///
+/// `fields` the storage fields of the contract
+/// `structs` the structs defined in the contract
+fn add_storage_advice(fields: &mut [ContractField], structs: &[Struct]) {
+    for field in fields.iter_mut() {
+        let value_type = match mapping_value_type(&field.field_type) {
+            Some(value_type) => value_type,
+            None => continue,
+        };
+        if value_type.starts_with("Vec<") {
+            field.comments.push(String::from(
+                "Sol2Ink: values of this mapping are vectors which are loaded and stored as a whole on every access, consider a mapping indexed by the position instead",
+            ));
+        } else if let Some(structure) = structs
+            .iter()
+            .find(|structure| structure.name.trim() == value_type)
+            .filter(|structure| structure.fields.len() > LARGE_STRUCT_FIELDS)
+        {
+            field.comments.push(format!(
+                "Sol2Ink: values of this mapping are structs with {} fields which are loaded and stored as a whole on every access, consider splitting them into several mappings",
+                structure.fields.len()
+            ));
+        }
+    }
+}
+
+/// Splits a `Mapping` type into the type of its keys and the type of its values
+///
+/// returns `None` if the type is not a mapping
+fn split_mapping_type(field_type: &str) -> Option<(&str, &str)> {
+    let inner = field_type.strip_prefix("Mapping<")?.strip_suffix('>')?;
+    let mut depth = 0;
+    let mut separator = None;
+    for (index, ch) in inner.char_indices() {
+        match ch {
+            '(' | '<' | '[' => depth += 1,
+            ')' | '>' | ']' => depth -= 1,
+            COMMA if depth == 0 => separator = Some(index),
+            _ => {}
+        }
+    }
+    separator.map(|index| (inner[..index].trim(), inner[index + 1..].trim()))
+}
+
+/// Returns the type of the values of a `Mapping` type, or `None` if the type is not a mapping
+fn mapping_value_type(field_type: &str) -> Option<&str> {
+    split_mapping_type(field_type).map(|(_, value_type)| value_type)
+}
+
+/// Returns the count of the keys of a `Mapping` type, or `None` if the type is not a mapping
+///
+/// Nested solidity mappings are represented by a single `Mapping` with a tuple key
+fn mapping_key_count(field_type: &str) -> Option<usize> {
+    let (key_type, _) = split_mapping_type(field_type)?;
+    match key_type.strip_prefix('(') {
+        Some(tuple) => Some(split_args(tuple.strip_suffix(')')?).len()),
+        None => Some(1),
+    }
+}
+
+/// Creates the statement which assigns the value to the target expression
+///
+/// Values of mappings are written with `insert`, fields of structs stored in mappings
+/// are modified on a copy of the struct, which is then inserted back to the mapping
+///
+/// `left` the target of the assignment
+/// `right` the assigned value
+/// `operation` the assign operation
+fn assign(left: Expression, right: Expression, operation: Operation) -> Statement {
+    match left {
+        Expression::Mapping(name, indices, None) => {
+            let converted_operation = match operation {
+                Operation::AddAssign => Operation::Add,
+                Operation::MulAssign => Operation::Mul,
+                Operation::DivAssign => Operation::Div,
+                Operation::SubtractAssign => Operation::Subtract,
+                _ => operation,
+            };
+            let value = match converted_operation {
+                Operation::Add | Operation::Mul | Operation::Div | Operation::Subtract => {
+                    Expression::Arithmetic(
+                        bx!(Expression::Mapping(name.clone(), indices.clone(), None)),
+                        bx!(right),
+                        converted_operation,
+                    )
+                }
+                _ => right,
+            };
+            // the contract can not be borrowed by a call while its mapping is borrowed by insert
+            if calls_contract(&value) {
+                let value_name = match name.as_ref() {
+                    Expression::Member(mapping_name, _) => format!("{mapping_name}_value"),
+                    _ => String::from("value"),
+                };
+                return Statement::Group(vec![
+                    Statement::Declaration(value_name.clone(), String::new(), Some(value), false),
+                    Statement::FunctionCall(Expression::Mapping(
+                        name,
+                        indices,
+                        Some(bx!(Expression::Member(value_name, None))),
+                    )),
+                ])
+            }
+            Statement::FunctionCall(Expression::Mapping(name, indices, Some(bx!(value))))
+        }
+        Expression::WithSelector(owner, field)
+            if matches!(*owner, Expression::Mapping(_, _, None)) =>
+        {
+            assign_in_mapping_value(
+                *owner,
+                |entry| Expression::WithSelector(bx!(entry), field),
+                right,
+                operation,
+            )
+        }
+        Expression::ArrayIndex(owner, index)
+            if matches!(*owner, Expression::Mapping(_, _, None)) =>
+        {
+            assign_in_mapping_value(
+                *owner,
+                |entry| Expression::ArrayIndex(bx!(entry), index),
+                right,
+                operation,
+            )
+        }
+        _ => Statement::Assign(left, right, operation),
+    }
+}
+
+/// Creates the statements which modify a part of a value stored in a mapping
+///
+/// The value is read into a local variable, modified and inserted back to the mapping
+///
+/// `mapping` the read of the mapping value
+/// `target` creates the modified part of the value from the local variable
+/// `right` the assigned value
+/// `operation` the assign operation
+fn assign_in_mapping_value(
+    mapping: Expression,
+    target: impl FnOnce(Expression) -> Expression,
+    right: Expression,
+    operation: Operation,
+) -> Statement {
+    let (name, indices) = match mapping {
+        Expression::Mapping(name, indices, None) => (name, indices),
+        mapping => return Statement::Assign(target(mapping), right, operation),
+    };
+    let entry_name = match name.as_ref() {
+        Expression::Member(mapping_name, _) => format!("{mapping_name}_entry"),
+        _ => String::from("entry"),
+    };
+    let entry = Expression::Member(entry_name.clone(), None);
+    Statement::Group(vec![
+        Statement::Declaration(
+            entry_name,
+            String::new(),
+            Some(Expression::Mapping(name.clone(), indices.clone(), None)),
+            false,
+        ),
+        Statement::Assign(target(entry.clone()), right, operation),
+        Statement::FunctionCall(Expression::Mapping(name, indices, Some(bx!(entry)))),
+    ])
+}
+
+/// Marks the declarations of local variables which are later assigned as mutable
+///
+/// `statements` the parsed statements of a function, modifier or constructor
+fn mark_mutable_declarations(statements: &mut [Statement]) {
+    let assigned = assigned_locals(statements);
+    visit_statements(statements, &mut |statement| {
+        if let Statement::Declaration(name, _, _, mutable) = statement {
+            *mutable = assigned.contains(name);
+        }
+    });
+}
+
+/// Writes the modified storage pointer locals back to the mapping they were read from,
+/// at the end of their scope and before each return inside of it
+///
+/// `statements` the statements of a block
+/// `pointers` the names of the storage pointer locals
+fn write_back_storage_pointers(statements: &mut Vec<Statement>, pointers: &HashSet<String>) {
+    if pointers.is_empty() {
+        return
+    }
+    for statement in statements.iter_mut() {
+        match statement {
+            Statement::Catch(inner)
+            | Statement::Else(inner)
+            | Statement::ElseIf(_, inner)
+            | Statement::For(_, _, inner)
+            | Statement::Group(inner)
+            | Statement::If(_, inner)
+            | Statement::Loop(_, _, _, inner)
+            | Statement::Try(inner)
+            | Statement::While(_, _, _, inner) => write_back_storage_pointers(inner, pointers),
+            _ => {}
+        }
+    }
+
+    let mut write_backs = Vec::new();
+    for index in 0..statements.len() {
+        let (head, rest) = statements.split_at_mut(index + 1);
+        let declaration = match &head[index] {
+            Statement::Group(inner) => inner.last(),
+            statement => Some(statement),
+        };
+        if let Some(Statement::Declaration(
+            name,
+            _,
+            Some(Expression::Mapping(mapping, indices, None)),
+            _,
+        )) = declaration
+        {
+            if pointers.contains(name) && assigned_locals(rest).contains(name) {
+                write_backs.push((
+                    index,
+                    Statement::FunctionCall(Expression::Mapping(
+                        mapping.clone(),
+                        indices.clone(),
+                        Some(bx!(Expression::Member(name.clone(), None))),
+                    )),
+                ));
+            }
+        }
+    }
+
+    // the scopes are split from the back, so the indices of the declarations stay valid
+    for (index, write_back) in write_backs.into_iter().rev() {
+        let mut scope = statements.split_off(index + 1);
+        insert_before_return(&mut scope, &write_back);
+        if !matches!(scope.last(), Some(Statement::Return(_))) {
+            scope.push(write_back);
+        }
+        statements.append(&mut scope);
+    }
+}
+
+/// Inserts the statement before each `return`, including the returns of nested blocks
+///
+/// `statements` the statements of a block
+/// `statement` the statement which is executed before returning
+fn insert_before_return(statements: &mut Vec<Statement>, statement: &Statement) {
+    let mut index = 0;
+    while index < statements.len() {
+        match &mut statements[index] {
+            Statement::Return(_) => {
+                statements.insert(index, statement.clone());
+                index += 1;
+            }
+            Statement::Catch(inner)
+            | Statement::Else(inner)
+            | Statement::ElseIf(_, inner)
+            | Statement::For(_, _, inner)
+            | Statement::Group(inner)
+            | Statement::If(_, inner)
+            | Statement::Loop(_, _, _, inner)
+            | Statement::Try(inner)
+            | Statement::While(_, _, _, inner) => insert_before_return(inner, statement),
+            _ => {}
+        }
+        index += 1;
+    }
+}
+
+/// Inserts the statement before each `continue` of the loop, `continue` of nested loops is kept
+///
+/// `statements` the body of the loop
+/// `statement` the statement which is executed at the end of each iteration
+fn insert_before_continue(statements: &mut [Statement], statement: &Statement) {
+    for current in statements.iter_mut() {
+        match current {
+            Statement::Continue => {
+                *current = Statement::Group(vec![statement.clone(), Statement::Continue])
+            }
+            Statement::Catch(inner)
+            | Statement::Else(inner)
+            | Statement::ElseIf(_, inner)
+            | Statement::Group(inner)
+            | Statement::If(_, inner)
+            | Statement::Try(inner) => insert_before_continue(inner, statement),
+            Statement::Ternary(_, if_true, if_false) => {
+                insert_before_continue(std::slice::from_mut(if_true.as_mut()), statement);
+                insert_before_continue(std::slice::from_mut(if_false.as_mut()), statement);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the names of the local variables which are modified by the statements
+fn assigned_locals(statements: &mut [Statement]) -> HashSet<String> {
+    let mut assigned = HashSet::new();
+    visit_statements(statements, &mut |statement| {
+        match statement {
+            Statement::Assign(left, _, _) => assigned.extend(assigned_local(left)),
+            Statement::FunctionCall(Expression::Mapping(mapping, _, Some(_))) => {
+                assigned.extend(assigned_local(mapping))
+            }
+            Statement::FunctionCall(Expression::WithSelector(left, right)) if matches!(right.as_ref(), Expression::FunctionCall(name, ..) if name == "push" || name == "pop") => {
+                assigned.extend(assigned_local(left))
+            }
+            _ => {}
+        }
+    });
+    assigned
+}
+
+/// Returns the loop variable and the range of a counting `for` loop, like
+/// `for (uint i = 0; i < n; i++)`, which is translated to `for i in 0..n`
+///
+/// The loop variable and the bound must not be modified inside the loop,
+/// as the range is evaluated only once
+///
+/// `assignment` the initialization of the loop variable
+/// `condition` the condition of the loop
+/// `modification` the statement executed after each iteration
+/// `statements` the body of the loop
+///
+/// returns None if the loop is not a counting loop
+fn counting_range(
+    assignment: &Statement,
+    condition: &Expression,
+    modification: &Statement,
+    statements: &mut [Statement],
+) -> Option<(String, Expression)> {
+    let (variable, start) = match assignment {
+        Statement::Declaration(variable, _, Some(start), _) => (variable, start),
+        _ => return None,
+    };
+    let (end, inclusive) = match condition {
+        Expression::Condition(condition)
+            if condition.left == Expression::Member(variable.clone(), None) =>
+        {
+            match condition.operation {
+                Operation::LessThan => (condition.right.as_ref()?, false),
+                Operation::LessThanEqual => (condition.right.as_ref()?, true),
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let step = match modification {
+        Statement::Assign(
+            Expression::Member(name, None),
+            Expression::Literal(step),
+            Operation::AddAssign,
+        ) if name == variable => step.parse::<usize>().ok().filter(|step| *step > 0)?,
+        _ => return None,
+    };
+
+    let assigned = assigned_locals(statements);
+    let constant_end = match end {
+        Expression::Literal(_) | Expression::Constant(_) => true,
+        Expression::Member(name, None) => !assigned.contains(name),
+        _ => false,
+    };
+    if assigned.contains(variable) || !constant_end {
+        return None
+    }
+
+    let range = if inclusive {
+        quote!(#start..=#end)
+    } else {
+        quote!(#start..#end)
+    };
+    let range = if step > 1 {
+        let step = Literal::usize_unsuffixed(step);
+        quote!((#range).step_by(#step))
+    } else {
+        range
+    };
+    Some((variable.clone(), Expression::Literal(range.to_string())))
+}
+
+/// Returns true if any of the statements could not be translated
+///
+/// `statements` the parsed statements of a function
+fn has_untranslated(statements: &mut [Statement]) -> bool {
+    let mut untranslated = false;
+    visit_statements(statements, &mut |statement| {
+        if matches!(statement, Statement::Comment(comment) if comment.starts_with(NOT_IMPLEMENTED))
+        {
+            untranslated = true;
+        }
+    });
+    untranslated
+}
+
+/// Returns the name of the local variable which is modified by assigning to the expression
+fn assigned_local(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::Member(name, None) => Some(name.clone()),
+        Expression::ArrayIndex(array, _) => assigned_local(array),
+        Expression::Mapping(mapping, ..) => assigned_local(mapping),
+        Expression::WithSelector(left, _) => assigned_local(left),
+        _ => None,
+    }
+}
+
+/// Calls the visitor on each of the statements and on all statements nested in them
+fn visit_statements(statements: &mut [Statement], visitor: &mut dyn FnMut(&mut Statement)) {
+    for statement in statements.iter_mut() {
+        visitor(statement);
+        match statement {
+            Statement::Catch(inner)
+            | Statement::Else(inner)
+            | Statement::ElseIf(_, inner)
+            | Statement::For(_, _, inner)
+            | Statement::Group(inner)
+            | Statement::If(_, inner)
+            | Statement::Try(inner) => visit_statements(inner, visitor),
+            Statement::Loop(assign, _, modification, inner)
+            | Statement::While(assign, _, modification, inner) => {
+                for nested in assign.iter_mut().chain(modification.iter_mut()) {
+                    visit_statements(std::slice::from_mut(nested.as_mut()), visitor);
+                }
+                visit_statements(inner, visitor);
+            }
+            Statement::Ternary(_, if_true, if_false) => {
+                visit_statements(std::slice::from_mut(if_true.as_mut()), visitor);
+                visit_statements(std::slice::from_mut(if_false.as_mut()), visitor);
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Parses the modifiers of a function
 ///
 /// `attributes` the raw representation of the attributes of the function
@@ -2641,45 +5454,6 @@ fn parse_modifiers(attributes: &str) -> Vec<Expression> {
     }
 }
 
-/// Converts size of solidity int argument to match rust int size
-/// we use u128 or i128 for integers bigger than or equal to 128 bits
-/// we use u64 or i64 for integers bigger than or equal to 64 bits
-/// we use u32 or i32 for integers bigger than or equal to 32 bits
-/// we use u32 or i16 for integers bigger than or equal to 16 bits
-/// we use u8 or i8 for the smallest integers
-///
-/// Returns the updated int argument or the original argument if the argument provided is not an integer
-fn convert_int(arg_type: String) -> String {
-    let regex_int: Regex = Regex::new(
-        r#"(?x)^\s*
-        (?P<int_type>u*int)
-        (?P<int_size>[0-9]*)
-        \s*$"#,
-    )
-    .unwrap();
-
-    let int_type_raw = capture_regex(&regex_int, &arg_type, "int_type");
-    if let Some(int_type) = int_type_raw {
-        let int_size_raw = capture_regex(&regex_int, &arg_type, "int_size").unwrap();
-        let int_size_original = if int_size_raw.is_empty() {
-            128
-        } else {
-            int_size_raw.parse::<i32>().unwrap()
-        };
-        let int_size = match int_size_original {
-            i if i <= 8 => 8,
-            i if i <= 16 => 16,
-            i if i <= 32 => 32,
-            i if i <= 64 => 64,
-            _ => 128,
-        };
-
-        return format!("{int_type}{int_size}")
-    }
-
-    arg_type
-}
-
 /// Skips the characters in a char array until one of characters specified is found
 ///
 /// `chars` the char array where to skip the characters
@@ -20,7 +20,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use std::collections::HashSet;
+use std::{
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
+    fmt,
+};
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum ContractType {
@@ -33,12 +39,15 @@ pub struct Contract {
     pub fields: Vec<ContractField>,
     pub constructor: Function,
     pub events: Vec<Event>,
+    pub errors: Vec<CustomError>,
     pub enums: Vec<Enum>,
     pub structs: Vec<Struct>,
     pub functions: Vec<Function>,
     pub imports: HashSet<String>,
     pub contract_doc: Vec<String>,
     pub modifiers: Vec<Modifier>,
+    pub helpers: BTreeSet<Helper>,
+    pub base_contracts: Vec<String>,
 }
 
 pub struct Interface {
@@ -60,6 +69,7 @@ pub struct ContractField {
     pub constant: bool,
 }
 
+#[derive(Clone)]
 pub struct Modifier {
     pub header: FunctionHeader,
     pub statements: Vec<Statement>,
@@ -80,12 +90,26 @@ pub struct EventField {
     pub name: String,
 }
 
+#[derive(Clone)]
+pub struct CustomError {
+    pub name: String,
+    pub fields: Vec<String>,
+    pub comments: Vec<String>,
+}
+
+#[derive(Clone)]
 pub struct Enum {
     pub name: String,
-    pub values: Vec<String>,
+    pub values: Vec<EnumValue>,
     pub comments: Vec<String>,
 }
 
+#[derive(Clone)]
+pub struct EnumValue {
+    pub name: String,
+    pub discriminant: Option<String>,
+}
+
 #[derive(Clone)]
 pub struct Struct {
     pub name: String,
@@ -103,6 +127,8 @@ pub struct StructField {
 pub struct Function {
     pub header: FunctionHeader,
     pub body: Vec<Statement>,
+    /// at least one statement of the body could not be translated
+    pub untranslated: bool,
 }
 
 #[derive(Default, Clone)]
@@ -117,20 +143,24 @@ pub struct FunctionHeader {
     pub modifiers: Vec<Expression>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct FunctionParam {
     pub name: String,
     pub param_type: String,
+    /// the type of the parameter in the Solidity source, empty for generated functions
+    pub solidity_type: String,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Statement {
     AssemblyEnd,
     Assign(Expression, Expression, Operation),
+    Break,
     Catch(Vec<Statement>),
     CatchEnd,
     Comment(String),
-    Declaration(String, String, Option<Expression>),
+    Continue,
+    Declaration(String, String, Option<Expression>, bool),
     Loop(
         Option<Box<Statement>>,
         Expression,
@@ -140,14 +170,16 @@ pub enum Statement {
     Else(Vec<Statement>),
     ElseIf(Condition, Vec<Statement>),
     Emit(String, Vec<Expression>),
+    For(String, Expression, Vec<Statement>),
     FunctionCall(Expression),
     Group(Vec<Statement>),
     If(Condition, Vec<Statement>),
     IfEnd,
     ModifierBody,
-    Raw(String),
+    Raw(String, Location),
     Require(Condition, String),
     Return(Expression),
+    Revert(String, Vec<Expression>, bool),
     Ternary(Condition, Box<Statement>, Box<Statement>),
     Try(Vec<Statement>),
     TryEnd,
@@ -224,19 +256,31 @@ impl Operation {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Expression {
     Arithmetic(Box<Expression>, Box<Expression>, Operation),
+    ArrayIndex(Box<Expression>, Box<Expression>),
+    BaseCall(String, Vec<Expression>, String, bool, bool),
+    BlockNumber(Option<String>),
+    BlockTimestamp(Option<String>),
     Cast(bool, String, Box<Expression>),
+    CheckedArithmetic(Box<Expression>, Box<Expression>, Operation, bool),
     Condition(Box<Condition>),
     Constant(String),
     Enclosed(Box<Expression>),
+    EnvAccountId(Option<String>),
+    EnvBalance(Option<String>),
     EnvCaller(Option<String>),
+    ExternalCall(String, String, Box<Expression>, Vec<Expression>, bool),
     FunctionCall(String, Vec<Expression>, Option<String>, bool),
+    HelperCall(Helper, Vec<Expression>),
     IsZero(Box<Expression>),
+    Length(Box<Expression>),
     Literal(String),
     Logical(Box<Expression>, Operation, Box<Expression>),
     Member(String, Option<String>),
     Mapping(Box<Expression>, Vec<Expression>, Option<Box<Expression>>),
+    MappingRemove(Box<Expression>, Vec<Expression>),
     Modifier(String),
     NewArray(String, Box<Expression>),
+    NotImplemented(String),
     StructArg(String, Box<Expression>),
     StructInit(String, Vec<Expression>),
     Ternary(Box<Condition>, Box<Expression>, Box<Expression>),
@@ -245,6 +289,192 @@ pub enum Expression {
     ZeroAddressInto,
 }
 
+/// Helper functions which are generated into the contract when needed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Helper {
+    Bytes32ToString,
+    StringToBytes32,
+    ShlBytes,
+    ShrBytes,
+    AndBytes,
+    OrBytes,
+    XorBytes,
+}
+
+impl Helper {
+    /// returns the name of the generated helper function
+    pub fn name(&self) -> &'static str {
+        match self {
+            Helper::Bytes32ToString => "bytes32_to_string",
+            Helper::StringToBytes32 => "string_to_bytes32",
+            Helper::ShlBytes => "shl_bytes",
+            Helper::ShrBytes => "shr_bytes",
+            Helper::AndBytes => "and_bytes",
+            Helper::OrBytes => "or_bytes",
+            Helper::XorBytes => "xor_bytes",
+        }
+    }
+}
+
+/// The location of a statement in the Solidity source code
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl fmt::Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "L{}:C{}", self.line, self.column)
+    }
+}
+
+/// A construct of the Solidity source code which could not be translated
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub location: Location,
+    pub message: String,
+}
+
+/// Base contracts which are implemented by OpenBrush
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OpenBrushBase {
+    AccessControl,
+    Ownable,
+    PSP22,
+    PSP34,
+}
+
+impl OpenBrushBase {
+    /// returns the OpenBrush implementation of the Solidity base contract
+    pub fn from_name(name: &str) -> Option<OpenBrushBase> {
+        match name {
+            "AccessControl" => Some(OpenBrushBase::AccessControl),
+            "Ownable" => Some(OpenBrushBase::Ownable),
+            "ERC20" | "PSP22" => Some(OpenBrushBase::PSP22),
+            "ERC721" | "PSP34" => Some(OpenBrushBase::PSP34),
+            _ => None,
+        }
+    }
+
+    /// returns the name of the implemented trait
+    pub fn trait_name(&self) -> &'static str {
+        match self {
+            OpenBrushBase::AccessControl => "AccessControl",
+            OpenBrushBase::Ownable => "Ownable",
+            OpenBrushBase::PSP22 => "PSP22",
+            OpenBrushBase::PSP34 => "PSP34",
+        }
+    }
+
+    /// returns the module of OpenBrush contracts, which is also the name of the crate feature
+    pub fn module(&self) -> &'static str {
+        match self {
+            OpenBrushBase::AccessControl => "access_control",
+            OpenBrushBase::Ownable => "ownable",
+            OpenBrushBase::PSP22 => "psp22",
+            OpenBrushBase::PSP34 => "psp34",
+        }
+    }
+
+    /// returns the name of the storage field holding the data of the base
+    pub fn storage_field(&self) -> &'static str {
+        match self {
+            OpenBrushBase::AccessControl => "access",
+            OpenBrushBase::Ownable => "ownable",
+            OpenBrushBase::PSP22 => "psp22",
+            OpenBrushBase::PSP34 => "psp34",
+        }
+    }
+
+    /// returns the error type returned by the functions of the base
+    pub fn error(&self) -> &'static str {
+        match self {
+            OpenBrushBase::AccessControl => "AccessControlError",
+            OpenBrushBase::Ownable => "OwnableError",
+            OpenBrushBase::PSP22 => "PSP22Error",
+            OpenBrushBase::PSP34 => "PSP34Error",
+        }
+    }
+
+    /// returns the constants defined by the base
+    pub fn constants(&self) -> &'static [&'static str] {
+        match self {
+            OpenBrushBase::AccessControl => &["DEFAULT_ADMIN_ROLE"],
+            _ => &[],
+        }
+    }
+
+    /// returns the OpenBrush function which has to be called in the constructor
+    /// with the caller, as the Solidity base initializes its state in its own constructor
+    pub fn initializer(&self) -> Option<&'static str> {
+        match self {
+            OpenBrushBase::Ownable => Some("_init_with_owner"),
+            _ => None,
+        }
+    }
+
+    /// returns the OpenBrush function implementing the Solidity function of the base
+    /// and whether the function returns `Result`
+    pub fn function(&self, name: &str) -> Option<(&'static str, bool)> {
+        match (self, name) {
+            (OpenBrushBase::AccessControl, "hasRole") => Some(("has_role", false)),
+            (OpenBrushBase::AccessControl, "getRoleAdmin") => Some(("get_role_admin", false)),
+            (OpenBrushBase::AccessControl, "grantRole") => Some(("grant_role", true)),
+            (OpenBrushBase::AccessControl, "revokeRole") => Some(("revoke_role", true)),
+            (OpenBrushBase::AccessControl, "renounceRole") => Some(("renounce_role", true)),
+            (OpenBrushBase::AccessControl, "_setupRole" | "_grantRole") => {
+                Some(("_setup_role", false))
+            }
+            (OpenBrushBase::AccessControl, "_revokeRole") => Some(("_do_revoke_role", false)),
+            (OpenBrushBase::AccessControl, "_setRoleAdmin") => Some(("_set_role_admin", false)),
+            (OpenBrushBase::Ownable, "owner") => Some(("owner", false)),
+            (OpenBrushBase::Ownable, "transferOwnership") => Some(("transfer_ownership", true)),
+            (OpenBrushBase::Ownable, "renounceOwnership") => Some(("renounce_ownership", true)),
+            (OpenBrushBase::Ownable, "_transferOwnership") => Some(("_init_with_owner", false)),
+            (OpenBrushBase::PSP22, "totalSupply") => Some(("total_supply", false)),
+            (OpenBrushBase::PSP22, "balanceOf") => Some(("balance_of", false)),
+            (OpenBrushBase::PSP22, "allowance") => Some(("allowance", false)),
+            (OpenBrushBase::PSP22, "transfer") => Some(("transfer", true)),
+            (OpenBrushBase::PSP22, "transferFrom") => Some(("transfer_from", true)),
+            (OpenBrushBase::PSP22, "approve") => Some(("approve", true)),
+            (OpenBrushBase::PSP22, "increaseAllowance") => Some(("increase_allowance", true)),
+            (OpenBrushBase::PSP22, "decreaseAllowance") => Some(("decrease_allowance", true)),
+            (OpenBrushBase::PSP22, "_transfer") => Some(("_transfer_from_to", true)),
+            (OpenBrushBase::PSP22, "_mint") => Some(("_mint_to", true)),
+            (OpenBrushBase::PSP22, "_burn") => Some(("_burn_from", true)),
+            (OpenBrushBase::PSP22, "_approve") => Some(("_approve_from_to", true)),
+            (OpenBrushBase::PSP34, "totalSupply") => Some(("total_supply", false)),
+            (OpenBrushBase::PSP34, "balanceOf") => Some(("balance_of", false)),
+            (OpenBrushBase::PSP34, "ownerOf") => Some(("owner_of", false)),
+            (OpenBrushBase::PSP34, "_mint") => Some(("_mint_to", true)),
+            (OpenBrushBase::PSP34, "_burn") => Some(("_burn_from", true)),
+            _ => None,
+        }
+    }
+
+    /// returns the OpenBrush modifier implementing the Solidity modifier of the base
+    pub fn modifier(&self, name: &str) -> Option<&'static str> {
+        match (self, name) {
+            (OpenBrushBase::AccessControl, "onlyRole") => Some("only_role"),
+            (OpenBrushBase::Ownable, "onlyOwner") => Some("only_owner"),
+            _ => None,
+        }
+    }
+
+    /// returns true if the OpenBrush function takes an additional `data` argument
+    /// which its Solidity counterpart does not have
+    pub fn takes_data(&self, name: &str) -> bool {
+        matches!(
+            (self, name),
+            (
+                OpenBrushBase::PSP22,
+                "transfer" | "transferFrom" | "_transfer"
+            )
+        )
+    }
+}
+
 pub enum Block {
     Assembly,
     Catch,
@@ -20,10 +20,21 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
+use regex::Regex;
+
 const INK_VERSION: &str = "~3.3.0";
 const OPENBRUSH_VERSION: &str = "2.2.0";
 
-pub fn generate_cargo_toml() -> String {
+/// Generates the Cargo.toml of the transpiled contract
+///
+/// `openbrush_features` the features of OpenBrush used by the contract
+/// `primitive_types` if the contract uses the 256-bit integers of `primitive_types`
+/// `namespace` the name under which OpenBrush is imported by the contract
+pub fn generate_cargo_toml(
+    openbrush_features: &[String],
+    primitive_types: bool,
+    namespace: &str,
+) -> String {
     let mut out = String::new();
 
     out.push_str("[package]\n");
@@ -42,9 +53,23 @@ pub fn generate_cargo_toml() -> String {
     out.push_str(generate_ink_dependency("ink_engine", false, true).as_str());
     out.push_str("scale = { package = \"parity-scale-codec\", version = \"3\", default-features = false, features = [\"derive\"] }\n");
     out.push_str("scale-info = { version = \"2\", default-features = false, features = [\"derive\"], optional = true }\n");
-    out.push_str("openbrush = { version = \"");
+    out.push_str(namespace);
+    if namespace != "openbrush" {
+        out.push_str(" = { package = \"openbrush\", version = \"");
+    } else {
+        out.push_str(" = { version = \"");
+    }
     out.push_str(OPENBRUSH_VERSION);
-    out.push_str("\", default-features = false }\n");
+    out.push_str("\", default-features = false");
+    if !openbrush_features.is_empty() {
+        out.push_str(", features = [\"");
+        out.push_str(&openbrush_features.join("\", \""));
+        out.push_str("\"]");
+    }
+    out.push_str(" }\n");
+    if primitive_types {
+        out.push_str("primitive-types = { version = \"0.11\", default-features = false, features = [\"codec\", \"scale-info\"] }\n");
+    }
     out.push('\n');
     out.push_str("[lib]\n");
     out.push_str("name = \"sol_2_ink_generated\"\n");
@@ -63,7 +88,10 @@ pub fn generate_cargo_toml() -> String {
     out.push_str("\"scale/std\",\n");
     out.push_str("\"scale-info\",\n");
     out.push_str("\"scale-info/std\",\n");
-    out.push_str("\"openbrush/std\",\n");
+    out.push_str(&format!("\"{namespace}/std\",\n"));
+    if primitive_types {
+        out.push_str("\"primitive-types/std\",\n");
+    }
     out.push_str("]\n");
     out.push('\n');
 
@@ -89,3 +117,19 @@ fn generate_ink_dependency(crate_name: &str, derive: bool, optional: bool) -> St
 
     out
 }
+
+/// Returns the features of OpenBrush needed by the transpiled code
+///
+/// The features are named after the modules of OpenBrush contracts used by the code
+///
+/// `code` the transpiled code
+pub fn openbrush_features(code: &str) -> Vec<String> {
+    let regex = Regex::new(r"contracts::(?P<module>[a-z0-9_]+)::").unwrap();
+    let mut features = regex
+        .captures_iter(code)
+        .map(|captures| captures["module"].to_owned())
+        .collect::<Vec<String>>();
+    features.sort();
+    features.dedup();
+    features
+}